@@ -8,6 +8,8 @@
 
 //! Implement built-in traits in [`::std::cmp`].
 //!
+//! `PartialEq` and `Eq` are derived with the struct, so constants can be used in patterns.
+//!
 //! [`::std::cmp`]: https://doc.rust-lang.org/std/cmp/index.html#traits
 
 use crate::fixed_hash::HashConstructor;
@@ -16,48 +18,16 @@ use quote::quote;
 
 impl HashConstructor {
     pub fn impl_traits_std_cmp(&self) {
-        self.impl_traits_std_cmp_partialeq();
-        self.impl_traits_std_cmp_eq();
         self.impl_traits_std_cmp_partialord();
         self.impl_traits_std_cmp_ord();
     }
 
-    fn impl_traits_std_cmp_partialeq(&self) {
-        let name = &self.ts.name;
-        let lidx = utils::pure_uint_list_to_ts(0..self.info.unit_amount);
-        let ridx = lidx.clone();
-        let part = quote!(
-            impl ::std::cmp::PartialEq for #name {
-                #[inline]
-                fn eq(&self, other: &Self) -> bool {
-                    let lhs = self.inner();
-                    let rhs = other.inner();
-                    #(
-                        if lhs[#lidx] != rhs[#ridx] {
-                            return false;
-                        }
-                    )*
-                    true
-                }
-            }
-        );
-        self.implt(part);
-    }
-
-    fn impl_traits_std_cmp_eq(&self) {
-        let name = &self.ts.name;
-        let part = quote!(
-            impl ::std::cmp::Eq for #name {}
-        );
-        self.implt(part);
-    }
-
     fn impl_traits_std_cmp_partialord(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::cmp::PartialOrd for #name {
+            impl ::core::cmp::PartialOrd for #name {
                 #[inline]
-                fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
                     Some(self.cmp(other))
                 }
             }
@@ -69,22 +39,22 @@ impl HashConstructor {
         let name = &self.ts.name;
         let idx = utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let part = quote!(
-            impl ::std::cmp::Ord for #name {
+            impl ::core::cmp::Ord for #name {
                 #[inline]
-                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
                     let lhs = self.inner();
                     let rhs = other.inner();
                     #({
                         let idx = #idx;
                         if lhs[idx] != rhs[idx] {
                             return if lhs[idx] > rhs[idx] {
-                                ::std::cmp::Ordering::Greater
+                                ::core::cmp::Ordering::Greater
                             } else {
-                                ::std::cmp::Ordering::Less
+                                ::core::cmp::Ordering::Less
                             };
                         }
                     })*
-                    ::std::cmp::Ordering::Equal
+                    ::core::cmp::Ordering::Equal
                 }
             }
         );
@@ -16,19 +16,20 @@ use quote::quote;
 impl HashConstructor {
     pub fn impl_traits_std_convert(&self) {
         self.impl_traits_std_convert_from_as();
+        self.impl_traits_std_convert_borrow();
         self.impl_traits_std_convert_from_array();
     }
 
     fn impl_traits_std_convert_from_as(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::convert::AsRef<[u8]> for #name {
+            impl ::core::convert::AsRef<[u8]> for #name {
                 #[inline]
                 fn as_ref(&self) -> &[u8] {
                     &self.inner()[..]
                 }
             }
-            impl ::std::convert::AsMut<[u8]> for #name {
+            impl ::core::convert::AsMut<[u8]> for #name {
                 #[inline]
                 fn as_mut(&mut self) -> &mut [u8] {
                     &mut self.mut_inner()[..]
@@ -38,23 +39,43 @@ impl HashConstructor {
         self.implt(part);
     }
 
+    // `Hash`, `Eq` and `Ord` are consistent with `[u8]`, so a hash can be looked up by bytes.
+    fn impl_traits_std_convert_borrow(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            impl ::core::borrow::Borrow<[u8]> for #name {
+                #[inline]
+                fn borrow(&self) -> &[u8] {
+                    &self.inner()[..]
+                }
+            }
+            impl ::core::borrow::BorrowMut<[u8]> for #name {
+                #[inline]
+                fn borrow_mut(&mut self) -> &mut [u8] {
+                    &mut self.mut_inner()[..]
+                }
+            }
+        );
+        self.implt(part);
+    }
+
     fn impl_traits_std_convert_from_array(&self) {
         let name = &self.ts.name;
         let inner_type = &self.ts.inner_type;
         let part = quote!(
-            impl ::std::convert::From<#inner_type> for #name {
+            impl ::core::convert::From<#inner_type> for #name {
                 #[inline]
                 fn from(bytes: #inner_type) -> Self {
                     Self::new(bytes)
                 }
             }
-            impl<'a> ::std::convert::From<&'a #inner_type> for #name {
+            impl<'a> ::core::convert::From<&'a #inner_type> for #name {
                 #[inline]
                 fn from(bytes: &'a #inner_type) -> Self {
                     Self::new(*bytes)
                 }
             }
-            impl ::std::convert::From<#name> for #inner_type {
+            impl ::core::convert::From<#name> for #inner_type {
                 #[inline]
                 fn from(hash: #name) -> Self {
                     hash.into_inner()
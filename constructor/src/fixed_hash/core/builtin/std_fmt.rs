@@ -17,6 +17,9 @@ use quote::quote;
 impl HashConstructor {
     pub fn impl_traits_std_fmt(&self) {
         self.impl_traits_std_fmt_debug();
+        self.util_fmt_padded();
+        self.defun_priv_fmt_short();
+        self.attach_struct_for_fmt_short();
         self.impl_traits_std_fmt_lowerhex();
         self.impl_traits_std_fmt_upperhex();
         self.impl_traits_std_fmt_display();
@@ -27,10 +30,19 @@ impl HashConstructor {
         let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let loop_unit_amount_skip_first = &utils::pure_uint_list_to_ts(1..self.info.unit_amount);
         let part = quote!(
-            impl ::std::fmt::Debug for #name {
+            /// Show the inner bytes of the hash.
+            #[inline]
+            pub fn debug_limbs(&self) -> impl ::core::fmt::Debug + '_ {
+                DebugLimbs(self)
+            }
+        );
+        self.defun(part);
+        let part = quote!(
+            struct DebugLimbs<'a>(&'a #name);
+            impl<'a> ::core::fmt::Debug for DebugLimbs<'a> {
                 #[inline]
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    let data = self.inner();
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let data = self.0.inner();
                     let alternate = f.alternate();
                     write!(f, stringify!(#name))?;
                     write!(f, " ( [")?;
@@ -49,35 +61,54 @@ impl HashConstructor {
                     }
                 }
             }
+            impl ::core::fmt::Debug for #name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    if f.alternate() {
+                        ::core::fmt::Debug::fmt(&self.debug_limbs(), f)
+                    } else {
+                        write!(f, "{:#x}", self)
+                    }
+                }
+            }
         );
         self.implt(part);
     }
 
-    fn impl_traits_std_fmt_base_16(&self, trait_name: &str, prefix_char: char, format_char: char) {
+    fn impl_traits_std_fmt_base_16(&self, trait_name: &str, chars: &str) {
         let name = &self.ts.name;
+        let unit_amount = &self.ts.unit_amount;
         let trait_name = utils::ident_to_ts(trait_name);
-        let prefix = format!("0{}", prefix_char);
-        let write_tpl_padded = format!("{{:02{}}}", format_char);
+        let chars = syn::LitByteStr::new(chars.as_bytes(), proc_macro2::Span::call_site());
         let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
-        let loop_write_tpl_padded =
-            &vec![write_tpl_padded.as_str(); self.info.unit_amount as usize];
+        let loop_high = &utils::pure_uint_list_to_ts((0..self.info.unit_amount).map(|x| x * 2));
+        let loop_low = &utils::pure_uint_list_to_ts((0..self.info.unit_amount).map(|x| x * 2 + 1));
         let part_core = if self.info.expand {
-            quote!(#(write!(f, #loop_write_tpl_padded, data[#loop_unit_amount])?;)*)
+            quote!(#(
+                buf[#loop_high] = CHARS[(data[#loop_unit_amount] >> 4) as usize];
+                buf[#loop_low] = CHARS[(data[#loop_unit_amount] & 0xf) as usize];
+            )*)
         } else {
-            quote!(for x in data.iter() {
-                write!(f, #write_tpl_padded, x)?;
+            quote!(for (i, x) in data.iter().enumerate() {
+                buf[i * 2] = CHARS[(x >> 4) as usize];
+                buf[i * 2 + 1] = CHARS[(x & 0xf) as usize];
             })
         };
         let part = quote!(
-            impl ::std::fmt::#trait_name for #name {
+            impl ::core::fmt::#trait_name for #name {
                 #[inline]
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    const CHARS: &[u8] = #chars;
                     let data = self.inner();
-                    if f.alternate() {
-                        write!(f, #prefix)?;
-                    }
+                    let mut buf = [0u8; #unit_amount * 2];
                     #part_core
-                    Ok(())
+                    if let Some(prec) = f.precision() {
+                        if prec < #unit_amount {
+                            return self._fmt_short(f, &buf[..]);
+                        }
+                    }
+                    // all characters are ASCII characters
+                    f.pad_integral(true, "0x", ::core::str::from_utf8(&buf[..]).unwrap())
                 }
             }
         );
@@ -85,11 +116,11 @@ impl HashConstructor {
     }
 
     pub fn impl_traits_std_fmt_lowerhex(&self) {
-        self.impl_traits_std_fmt_base_16("LowerHex", 'x', 'x');
+        self.impl_traits_std_fmt_base_16("LowerHex", "0123456789abcdef");
     }
 
     pub fn impl_traits_std_fmt_upperhex(&self) {
-        self.impl_traits_std_fmt_base_16("UpperHex", 'x', 'X');
+        self.impl_traits_std_fmt_base_16("UpperHex", "0123456789ABCDEF");
     }
 
     pub fn impl_traits_std_fmt_display(&self) {
@@ -120,9 +151,12 @@ impl HashConstructor {
             quote!(#(write!(f, #loop_write_tpl_padded, data[#loop_unit_amount])?;)*)
         };
         let part = quote!(
-            impl ::std::fmt::#trait_name for #name {
+            impl ::core::fmt::#trait_name for #name {
                 #[inline]
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    if f.precision().is_some() {
+                        return ::core::fmt::LowerHex::fmt(self, f);
+                    }
                     let data = self.inner();
                     if f.alternate() {
                         write!(f, "0x")?;
@@ -134,4 +168,86 @@ impl HashConstructor {
         );
         self.implt(part);
     }
+
+    fn util_fmt_padded(&self) {
+        self.util(utils::fmt_padded_ts());
+    }
+
+    fn defun_priv_fmt_short(&self) {
+        let utils_name = &self.ts.utils_name;
+        let part = quote!(
+            /// Write the leading and trailing hexadecimal digits, and omit the middle ones.
+            ///
+            /// The amount of digits on each side is the precision.
+            #[inline]
+            fn _fmt_short(
+                &self,
+                f: &mut ::core::fmt::Formatter,
+                digits: &[u8],
+            ) -> ::core::fmt::Result {
+                use core::fmt::Write;
+                let prec = f.precision().unwrap_or(0);
+                let len = digits.len();
+                // all characters are ASCII characters
+                let head = ::core::str::from_utf8(&digits[..prec]).unwrap();
+                let tail = ::core::str::from_utf8(&digits[(len - prec)..]).unwrap();
+                let prefix = if f.alternate() { "0x" } else { "" };
+                #utils_name::fmt_padded(f, prefix, prec * 2 + 1, |f| {
+                    f.write_str(head)?;
+                    f.write_char('\u{2026}')?;
+                    f.write_str(tail)
+                })
+            }
+            /// Show the hash as a short hexadecimal string, for example, `0x1234…cdef`.
+            #[inline]
+            pub fn short(&self) -> ShortHash<'_, Self> {
+                ShortHash(self)
+            }
+        );
+        self.defun(part);
+    }
+
+    fn attach_struct_for_fmt_short(&self) {
+        let utils_name = &self.ts.utils_name;
+        let part = quote!(
+            /// An adaptor to show a hash as a short hexadecimal string, for example, `0x1234…cdef`.
+            ///
+            /// Four digits are shown on each side by default, use the precision to change it.
+            #[derive(Clone, Copy)]
+            pub struct ShortHash<'a, H>(&'a H);
+            impl<'a, H: ::core::fmt::LowerHex> ::core::fmt::Display for ShortHash<'a, H> {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let prec = f.precision().unwrap_or(4);
+                    if f.width().is_none() {
+                        return write!(f, "{:#.*x}", prec, self.0);
+                    }
+                    // count the characters first, then pad them as a whole
+                    struct Counter(usize);
+                    impl ::core::fmt::Write for Counter {
+                        #[inline]
+                        fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                            self.0 += s.chars().count();
+                            Ok(())
+                        }
+                    }
+                    let mut counter = Counter(0);
+                    ::core::fmt::Write::write_fmt(
+                        &mut counter,
+                        format_args!("{:.*x}", prec, self.0),
+                    )?;
+                    #utils_name::fmt_padded(f, "0x", counter.0, |f| {
+                        write!(f, "{:.*x}", prec, self.0)
+                    })
+                }
+            }
+            impl<'a, H: ::core::fmt::LowerHex> ::core::fmt::Debug for ShortHash<'a, H> {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(self, f)
+                }
+            }
+        );
+        self.attach_common(part);
+    }
 }
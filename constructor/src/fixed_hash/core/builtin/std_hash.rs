@@ -8,6 +8,8 @@
 
 //! Implement built-in traits in [`::std::hash`].
 //!
+//! Hash as a byte slice, to be consistent with `Borrow<[u8]>`.
+//!
 //! [`::std::hash`]: https://doc.rust-lang.org/std/hash/index.html#traits
 
 use crate::fixed_hash::HashConstructor;
@@ -17,10 +19,10 @@ impl HashConstructor {
     pub fn impl_traits_std_hash(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::hash::Hash for #name {
+            impl ::core::hash::Hash for #name {
                 #[inline]
-                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
-                    state.write(&self.inner()[..])
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&self.inner()[..], state)
                 }
             }
         );
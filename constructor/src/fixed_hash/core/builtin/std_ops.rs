@@ -33,12 +33,13 @@ impl HashConstructor {
         let trait_name = utils::ident_to_ts(trait_name);
         let func_name = utils::ident_to_ts(func_name);
         let real_func = utils::ident_to_ts(realfunc);
+        let real_func_assign = utils::ident_to_ts(format!("{}_assign", realfunc).as_ref());
         let trait_assign_name = utils::ident_to_ts(format!("{}Assign", trait_name).as_ref());
         let func_assign_name = utils::ident_to_ts(format!("{}_assign", func_name).as_ref());
         let part = quote!(
-            impl<'a, Rhs> ::std::ops::#trait_name<Rhs> for &'a #name
+            impl<'a, Rhs> ::core::ops::#trait_name<Rhs> for &'a #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 type Output = #name;
                 #[inline]
@@ -46,43 +47,45 @@ impl HashConstructor {
                     self.#real_func(&other.into())
                 }
             }
-            impl<Rhs> ::std::ops::#trait_name<Rhs> for #name
+            impl<Rhs> ::core::ops::#trait_name<Rhs> for #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 type Output = #name;
                 #[inline]
-                fn #func_name(self, other: Rhs) -> Self::Output {
-                    self.#real_func(&other.into())
+                fn #func_name(mut self, other: Rhs) -> Self::Output {
+                    self.#real_func_assign(&other.into());
+                    self
                 }
             }
-            impl<Rhs> ::std::ops::#trait_assign_name<Rhs> for #name
+            impl<Rhs> ::core::ops::#trait_assign_name<Rhs> for #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 #[inline]
                 fn #func_assign_name(&mut self, other: Rhs) {
-                    *self = self.#real_func(&other.into());
+                    self.#real_func_assign(&other.into());
                 }
             }
-            impl<'a, 'b> ::std::ops::#trait_name<&'b #name> for &'a #name {
+            impl<'a, 'b> ::core::ops::#trait_name<&'b #name> for &'a #name {
                 type Output = #name;
                 #[inline]
                 fn #func_name(self, other: &#name) -> Self::Output {
                     self.#real_func(other)
                 }
             }
-            impl<'a> ::std::ops::#trait_name<&'a #name> for #name {
+            impl<'a> ::core::ops::#trait_name<&'a #name> for #name {
                 type Output = #name;
                 #[inline]
-                fn #func_name(self, other: &#name) -> Self::Output {
-                    self.#real_func(other)
+                fn #func_name(mut self, other: &#name) -> Self::Output {
+                    self.#real_func_assign(other);
+                    self
                 }
             }
-            impl<'a> ::std::ops::#trait_assign_name<&'a #name> for #name {
+            impl<'a> ::core::ops::#trait_assign_name<&'a #name> for #name {
                 #[inline]
                 fn #func_assign_name(&mut self, other: &#name) {
-                    *self = self.#real_func(other);
+                    self.#real_func_assign(other);
                 }
             }
         );
@@ -93,14 +96,14 @@ impl HashConstructor {
     fn impl_traits_std_ops_not(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl<'a> ::std::ops::Not for &'a #name {
+            impl<'a> ::core::ops::Not for &'a #name {
                 type Output = #name;
                 #[inline]
                 fn not(self) -> Self::Output {
                     self._not()
                 }
             }
-            impl ::std::ops::Not for #name {
+            impl ::core::ops::Not for #name {
                 type Output = #name;
                 #[inline]
                 fn not(self) -> Self::Output {
@@ -121,42 +124,42 @@ impl HashConstructor {
             let uint_name = utils::ident_to_ts(uint_name);
             let real_func = utils::ident_to_ts(format!("_ush{}", direction).as_ref());
             let part = quote!(
-                impl<'a, 'b> ::std::ops::#trait_name<&'a #uint_name> for &'b #name {
+                impl<'a, 'b> ::core::ops::#trait_name<&'a #uint_name> for &'b #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#uint_name) -> Self::Output {
                         self.#real_func(*other as u128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<#uint_name> for &'a #name {
+                impl<'a> ::core::ops::#trait_name<#uint_name> for &'a #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #uint_name) -> Self::Output {
                         self.#real_func(other as u128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<&'a #uint_name> for #name {
+                impl<'a> ::core::ops::#trait_name<&'a #uint_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#uint_name) -> Self::Output {
                         self.#real_func(*other as u128)
                     }
                 }
-                impl ::std::ops::#trait_name<#uint_name> for #name {
+                impl ::core::ops::#trait_name<#uint_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #uint_name) -> Self::Output {
                         self.#real_func(other as u128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_assign_name<&'a #uint_name> for #name {
+                impl<'a> ::core::ops::#trait_assign_name<&'a #uint_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: &#uint_name) {
                         let ret = self.#real_func(*other as u128);
                         *self = ret;
                     }
                 }
-                impl ::std::ops::#trait_assign_name<#uint_name> for #name {
+                impl ::core::ops::#trait_assign_name<#uint_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: #uint_name) {
                         let ret = self.#real_func(other as u128);
@@ -170,42 +173,42 @@ impl HashConstructor {
             let int_name = utils::ident_to_ts(int_name);
             let real_func = utils::ident_to_ts(format!("_ish{}", direction).as_ref());
             let part = quote!(
-                impl<'a, 'b> ::std::ops::#trait_name<&'a #int_name> for &'b #name {
+                impl<'a, 'b> ::core::ops::#trait_name<&'a #int_name> for &'b #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#int_name) -> Self::Output {
                         self.#real_func(*other as i128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<#int_name> for &'a #name {
+                impl<'a> ::core::ops::#trait_name<#int_name> for &'a #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #int_name) -> Self::Output {
                         self.#real_func(other as i128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<&'a #int_name> for #name {
+                impl<'a> ::core::ops::#trait_name<&'a #int_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#int_name) -> Self::Output {
                         self.#real_func(*other as i128)
                     }
                 }
-                impl ::std::ops::#trait_name<#int_name> for #name {
+                impl ::core::ops::#trait_name<#int_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #int_name) -> Self::Output {
                         self.#real_func(other as i128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_assign_name<&'a #int_name> for #name {
+                impl<'a> ::core::ops::#trait_assign_name<&'a #int_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: &#int_name) {
                         let ret = self.#real_func(*other as i128);
                         *self = ret;
                     }
                 }
-                impl ::std::ops::#trait_assign_name<#int_name> for #name {
+                impl ::core::ops::#trait_assign_name<#int_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: #int_name) {
                         let ret = self.#real_func(other as i128);
@@ -220,9 +223,9 @@ impl HashConstructor {
     fn impl_traits_std_ops_index(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl<Idx> ::std::ops::Index<Idx> for #name
+            impl<Idx> ::core::ops::Index<Idx> for #name
             where
-                Idx: ::std::slice::SliceIndex<[u8], Output = [u8]>,
+                Idx: ::core::slice::SliceIndex<[u8], Output = [u8]>,
             {
                 type Output = Idx::Output;
                 #[inline]
@@ -230,9 +233,9 @@ impl HashConstructor {
                     &self.inner()[index]
                 }
             }
-            impl<Idx> ::std::ops::IndexMut<Idx> for #name
+            impl<Idx> ::core::ops::IndexMut<Idx> for #name
             where
-                Idx: ::std::slice::SliceIndex<[u8], Output = [u8]>,
+                Idx: ::core::slice::SliceIndex<[u8], Output = [u8]>,
             {
                 #[inline]
                 fn index_mut(&mut self, index: Idx) -> &mut Idx::Output {
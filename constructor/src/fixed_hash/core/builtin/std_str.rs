@@ -18,7 +18,7 @@ impl HashConstructor {
         let name = &self.ts.name;
         let error_name = &self.ts.error_name;
         let part = quote!(
-            impl ::std::str::FromStr for #name {
+            impl ::core::str::FromStr for #name {
                 type Err = #error_name;
                 /// Convert from a hexadecimal string.
                 #[inline]
@@ -20,6 +20,7 @@ pub struct HashInformation {
     pub bits_size: u64,
     pub unit_amount: u64,
     pub expand: bool,
+    pub copy: bool,
 }
 
 impl ::std::convert::From<parsed::HashDefinition> for HashInformation {
@@ -31,12 +32,15 @@ impl ::std::convert::From<parsed::HashDefinition> for HashInformation {
         let unit_amount = attrs.size / 8;
 
         let expand = unit_amount <= 64;
+        // derive `Copy` or not
+        let copy = attrs.copy;
 
         Self {
             name,
             bits_size,
             unit_amount,
             expand,
+            copy,
         }
     }
 }
@@ -100,7 +104,7 @@ pub struct HashConstructor {
     // Outputs (once)
     common: Cell<Vec<TokenStream>>,
     // Outputs (errors)
-    errors: Cell<Vec<TokenStream>>,
+    errors: Cell<Vec<(TokenStream, TokenStream)>>,
     // Outputs (utils)
     utils: Cell<Vec<TokenStream>>,
     // Outputs (traits)
@@ -135,10 +139,20 @@ impl HashConstructor {
         let name = &self.ts.name;
         let feature = &self.ts.feature;
         let inner_type = &self.ts.inner_type;
+        let derives = if self.info.copy {
+            quote!(Clone, Copy, PartialEq, Eq)
+        } else {
+            quote!(Clone, PartialEq, Eq)
+        };
         let part = quote!(
             /// Fixed hash type.
             #[cfg(feature = #feature)]
-            #[derive(Clone)]
+            #[derive(#derives)]
+            #[cfg_attr(
+                feature = "support_zerocopy",
+                derive(zerocopy::FromBytes, zerocopy::AsBytes)
+            )]
+            #[repr(transparent)]
             pub struct #name(pub #inner_type);
         );
         self.attach_hash(part);
@@ -151,11 +165,20 @@ impl HashConstructor {
             if errors.is_empty() {
                 quote!()
             } else {
-                let errors = TokenStream::from_iter(errors);
+                let (variants, displays): (Vec<_>, Vec<_>) = errors.into_iter().unzip();
                 quote!(
-                    #[derive(Debug, Error)]
+                    #[derive(Debug)]
+                    #[cfg_attr(feature = "std", derive(Error))]
                     pub enum #error_name {
-                        #errors
+                        #(#variants)*
+                    }
+                    impl ::core::fmt::Display for #error_name {
+                        #[inline]
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                #(#displays)*
+                            }
+                        }
                     }
                 )
             }
@@ -269,10 +292,29 @@ impl HashConstructor {
         self.common.set(o);
     }
 
-    pub fn error(&self, part: TokenStream) {
+    /// Add a variant which wraps an inner error into the main error, and the conversion for it.
+    pub fn error(&self, variant: &str, inner: &str, explain: &str) {
+        let error_name = &self.ts.error_name;
+        let variant = utils::ident_to_ts(variant);
+        let inner = utils::ident_to_ts(inner);
+        let part = quote!(
+            #variant(#[cfg_attr(feature = "std", source)] #inner),
+        );
+        let display = quote!(
+            #error_name::#variant(err) => write!(f, #explain, err),
+        );
         let mut o = self.errors.take();
-        o.push(part);
+        o.push((part, display));
         self.errors.set(o);
+        let part = quote!(
+            impl ::core::convert::From<#inner> for #error_name {
+                #[inline]
+                fn from(err: #inner) -> Self {
+                    #error_name::#variant(err)
+                }
+            }
+        );
+        self.attach_common(part);
     }
 
     pub fn util(&self, part: TokenStream) {
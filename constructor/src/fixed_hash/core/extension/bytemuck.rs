@@ -0,0 +1,42 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`bytemuck`] for casting between plain data types.
+//!
+//! `Pod` requires `Copy`, so it is only implemented for the types with attribute `copy`.
+//!
+//! [`bytemuck`]: https://crates.io/crates/bytemuck
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_bytemuck(&self) {
+        self.with_bytemuck_impl();
+    }
+
+    fn with_bytemuck_impl(&self) {
+        let name = &self.ts.name;
+        let inner_type = &self.ts.inner_type;
+        let part = quote!(
+            // The struct is `#[repr(transparent)]` over an array of integers.
+            #[cfg(feature = "support_bytemuck")]
+            unsafe impl bytemuck::Zeroable for #name {}
+            #[cfg(feature = "support_bytemuck")]
+            unsafe impl bytemuck::TransparentWrapper<#inner_type> for #name {}
+        );
+        self.implt(part);
+        if self.info.copy {
+            let part = quote!(
+                #[cfg(feature = "support_bytemuck")]
+                unsafe impl bytemuck::Pod for #name {}
+            );
+            self.implt(part);
+        }
+    }
+}
@@ -10,6 +10,10 @@
 //!
 //! The dependencies should be in the `Cargo.toml`.
 
+mod arbitrary;
+mod bytemuck;
 mod heapsize;
+mod proptest;
+mod quickcheck;
 mod rand;
 mod serde;
@@ -0,0 +1,46 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`proptest`] for property testing.
+//!
+//! Values shrink byte by byte toward zero.
+//!
+//! [`proptest`]: https://crates.io/crates/proptest
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_proptest(&self) {
+        self.with_proptest_impl();
+    }
+
+    fn with_proptest_impl(&self) {
+        let name = &self.ts.name;
+        let unit_amount = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(feature = "support_proptest")]
+            impl proptest::arbitrary::Arbitrary for #name {
+                type Parameters = ();
+                type Strategy = proptest::strategy::BoxedStrategy<Self>;
+                #[inline]
+                fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+                    use proptest::strategy::Strategy;
+                    proptest::collection::vec(proptest::num::u8::ANY, #unit_amount)
+                        .prop_map(|bytes| {
+                            let mut inner = [0; #unit_amount];
+                            inner.copy_from_slice(&bytes[..]);
+                            Self::new(inner)
+                        })
+                        .boxed()
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`quickcheck`] for property testing.
+//!
+//! Generated values are mixed with edge cases, such as empty, full, single-bit and repeated
+//! bytes.
+//! Each shrunk value has fewer set bits, so counterexamples are minimal.
+//!
+//! [`quickcheck`]: https://crates.io/crates/quickcheck
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_quickcheck(&self) {
+        self.with_quickcheck_impl();
+    }
+
+    fn with_quickcheck_impl(&self) {
+        let name = &self.ts.name;
+        let bits_size = &self.ts.bits_size;
+        let part = quote!(
+            #[cfg(feature = "support_quickcheck")]
+            impl quickcheck::Arbitrary for #name {
+                fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                    let mut ret = Self::empty();
+                    match <u8 as quickcheck::Arbitrary>::arbitrary(g) % 6 {
+                        0 => {}
+                        1 => ret = Self::full(),
+                        2 => {
+                            let idx = <usize as quickcheck::Arbitrary>::arbitrary(g) % #bits_size;
+                            ret.set_bit(idx, true);
+                        }
+                        3 => ret = Self::repeat_byte(<u8 as quickcheck::Arbitrary>::arbitrary(g)),
+                        _ => {
+                            for byte in ret.mut_inner().iter_mut() {
+                                *byte = <u8 as quickcheck::Arbitrary>::arbitrary(g);
+                            }
+                        }
+                    }
+                    ret
+                }
+                fn shrink(&self) -> ::alloc::boxed::Box<dyn Iterator<Item = Self>> {
+                    let mut ret = ::alloc::vec::Vec::new();
+                    if let (Some(highest), Some(lowest)) = (self.highest_one(), self.lowest_one()) {
+                        ret.push(Self::empty());
+                        if highest != lowest {
+                            let mut cleared = self.clone();
+                            cleared.set_byte(highest / 8, 0);
+                            ret.push(cleared);
+                            let mut cleared = self.clone();
+                            cleared.set_bit(highest, false);
+                            ret.push(cleared);
+                            let mut cleared = self.clone();
+                            cleared.set_bit(lowest, false);
+                            ret.push(cleared);
+                        }
+                        ret.dedup();
+                    }
+                    ::alloc::boxed::Box::new(ret.into_iter())
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -16,6 +16,8 @@ use quote::quote;
 impl HashConstructor {
     pub fn with_serde(&self) {
         self.with_serde_defun_pub();
+        self.with_serde_helpers();
+        self.with_serde_helpers_impl();
     }
 
     fn with_serde_defun_pub(&self) {
@@ -28,13 +30,22 @@ impl HashConstructor {
                 where
                     S: serde::Serializer,
                 {
+                    if !serializer.is_human_readable() {
+                        use serde::ser::SerializeTuple;
+                        // fixed-size bytes, no length is required
+                        let mut tuple = serializer.serialize_tuple(#bytes_size)?;
+                        for byte in self.as_bytes() {
+                            tuple.serialize_element(byte)?;
+                        }
+                        return tuple.end();
+                    }
                     let bytes = self.as_bytes();
                     let mut dst = [0u8; #bytes_size * 2 + 2];
                     dst[0] = b'0';
                     dst[1] = b'x';
                     faster_hex::hex_encode(bytes, &mut dst[2..])
-                        .map_err(|e| serde::ser::Error::custom(&format!("{}", e)))?;
-                    serializer.serialize_str(unsafe { ::std::str::from_utf8_unchecked(&dst) })
+                        .map_err(serde::ser::Error::custom)?;
+                    serializer.serialize_str(unsafe { ::core::str::from_utf8_unchecked(&dst) })
                 }
             }
 
@@ -44,6 +55,43 @@ impl HashConstructor {
                 where
                     D: serde::Deserializer<'de>,
                 {
+                    struct BytesVisitor;
+
+                    impl<'b> serde::de::Visitor<'b> for BytesVisitor {
+                        type Value = #name;
+
+                        fn expecting(
+                            &self,
+                            formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            write!(formatter, "{} bytes", #bytes_size)
+                        }
+
+                        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: serde::de::SeqAccess<'b>,
+                        {
+                            let mut ret = #name::empty();
+                            for (idx, byte) in ret.as_bytes_mut().iter_mut().enumerate() {
+                                *byte = seq
+                                    .next_element()?
+                                    .ok_or_else(|| serde::de::Error::invalid_length(idx, &self))?;
+                            }
+                            Ok(ret)
+                        }
+
+                        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            #name::from_slice(v).map_err(|_| E::invalid_length(v.len(), &self))
+                        }
+                    }
+
+                    if !deserializer.is_human_readable() {
+                        return deserializer.deserialize_tuple(#bytes_size, BytesVisitor);
+                    }
+
                     struct Visitor;
 
                     impl<'b> serde::de::Visitor<'b> for Visitor {
@@ -51,8 +99,8 @@ impl HashConstructor {
 
                         fn expecting(
                             &self,
-                            formatter: &mut ::std::fmt::Formatter,
-                        ) -> ::std::fmt::Result {
+                            formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
                             write!(
                                 formatter,
                                 "a 0x-prefixed hex string with {} digits",
@@ -82,15 +130,105 @@ impl HashConstructor {
                                 ))
                             })
                         }
+                    }
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+        );
+        self.implt(part);
+    }
 
-                        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-                        where
-                            E: serde::de::Error,
-                        {
-                            self.visit_str(&v)
+    fn with_serde_helpers(&self) {
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            /// Helpers for `#[serde(with = "..")]` to use different representations for the hashes.
+            #[cfg(feature = "support_serde")]
+            pub mod serde_helpers {
+                /// The conversions which are used by the serde helpers.
+                pub trait HashSerde:
+                    Sized + serde::Serialize + for<'de> serde::Deserialize<'de>
+                {
+                    /// The size of the hash in bytes.
+                    const BYTES_SIZE: usize;
+                    /// Convert from a hexadecimal string with the full width.
+                    fn from_hex_value(value: &str) -> Result<Self, super::#error_name>;
+                }
+
+                /// Serialize as the default format, and deserialize from a hexadecimal string
+                /// with or without the `0x` prefix, in lowercase or uppercase.
+                ///
+                /// The default format is used if the format is not human readable.
+                pub mod lenient {
+                    use super::HashSerde;
+                    use core::{fmt, marker::PhantomData};
+
+                    struct Visitor<T>(PhantomData<T>);
+
+                    impl<'de, T: HashSerde> serde::de::Visitor<'de> for Visitor<T> {
+                        type Value = T;
+
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            write!(
+                                f,
+                                "a hex string with {} digits, the 0x prefix is optional",
+                                T::BYTES_SIZE * 2
+                            )
+                        }
+
+                        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> {
+                            let digits = if v.starts_with("0x") || v.starts_with("0X") {
+                                &v[2..]
+                            } else {
+                                v
+                            };
+                            if digits.len() != T::BYTES_SIZE * 2 {
+                                return Err(E::invalid_length(digits.len(), &self));
+                            }
+                            T::from_hex_value(digits).map_err(|err| {
+                                let expected = &self as &dyn serde::de::Expected;
+                                E::custom(format_args!("{}, expected {}", err, expected))
+                            })
                         }
                     }
-                    deserializer.deserialize_str(Visitor)
+
+                    /// Serialize the hash.
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: HashSerde,
+                        S: serde::Serializer,
+                    {
+                        value.serialize(serializer)
+                    }
+
+                    /// Deserialize the hash.
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: HashSerde,
+                        D: serde::Deserializer<'de>,
+                    {
+                        if deserializer.is_human_readable() {
+                            deserializer.deserialize_str(Visitor(PhantomData))
+                        } else {
+                            T::deserialize(deserializer)
+                        }
+                    }
+                }
+            }
+        );
+        self.attach_common(part);
+    }
+
+    fn with_serde_helpers_impl(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let bytes_size = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(feature = "support_serde")]
+            impl serde_helpers::HashSerde for #name {
+                const BYTES_SIZE: usize = #bytes_size;
+                #[inline]
+                fn from_hex_value(value: &str) -> Result<Self, #error_name> {
+                    Self::from_hex_str(value)
                 }
             }
         );
@@ -26,7 +26,7 @@ impl HashConstructor {
             quote!(
                 #[inline]
                 fn _bitand(&self, rhs: &Self) -> Self {
-                    let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                    let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let inner = self.inner();
                     let rhs = rhs.inner();
                     #({
@@ -36,8 +36,17 @@ impl HashConstructor {
                     Self::new(ret)
                 }
                 #[inline]
+                fn _bitand_assign(&mut self, rhs: &Self) {
+                    let inner = self.mut_inner();
+                    let rhs = rhs.inner();
+                    #({
+                        let idx = #loop_unit_amount;
+                        inner[idx] &= rhs[idx];
+                    })*
+                }
+                #[inline]
                 fn _bitor(&self, rhs: &Self) -> Self {
-                    let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                    let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let inner = self.inner();
                     let rhs = rhs.inner();
                     #({
@@ -47,8 +56,17 @@ impl HashConstructor {
                     Self::new(ret)
                 }
                 #[inline]
+                fn _bitor_assign(&mut self, rhs: &Self) {
+                    let inner = self.mut_inner();
+                    let rhs = rhs.inner();
+                    #({
+                        let idx = #loop_unit_amount;
+                        inner[idx] |= rhs[idx];
+                    })*
+                }
+                #[inline]
                 fn _bitxor(&self, rhs: &Self) -> Self {
-                    let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                    let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let inner = self.inner();
                     let rhs = rhs.inner();
                     #({
@@ -57,13 +75,22 @@ impl HashConstructor {
                     })*
                     Self::new(ret)
                 }
+                #[inline]
+                fn _bitxor_assign(&mut self, rhs: &Self) {
+                    let inner = self.mut_inner();
+                    let rhs = rhs.inner();
+                    #({
+                        let idx = #loop_unit_amount;
+                        inner[idx] ^= rhs[idx];
+                    })*
+                }
             )
         } else {
             quote!(
                 #[inline]
                 fn _bitand(&self, rhs: &Self) -> Self {
                     let mut ret: #inner_type =
-                        unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                        unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let rhs = rhs.inner();
                     for (idx, lhs) in self.inner().iter().enumerate() {
                         ret[idx] = lhs & rhs[idx];
@@ -71,9 +98,16 @@ impl HashConstructor {
                     Self::new(ret)
                 }
                 #[inline]
+                fn _bitand_assign(&mut self, rhs: &Self) {
+                    let rhs = rhs.inner();
+                    for (lhs, rhs) in self.mut_inner().iter_mut().zip(rhs.iter()) {
+                        *lhs &= rhs;
+                    }
+                }
+                #[inline]
                 fn _bitor(&self, rhs: &Self) -> Self {
                     let mut ret: #inner_type =
-                        unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                        unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let rhs = rhs.inner();
                     for (idx, lhs) in self.inner().iter().enumerate() {
                         ret[idx] = lhs | rhs[idx];
@@ -81,15 +115,29 @@ impl HashConstructor {
                     Self::new(ret)
                 }
                 #[inline]
+                fn _bitor_assign(&mut self, rhs: &Self) {
+                    let rhs = rhs.inner();
+                    for (lhs, rhs) in self.mut_inner().iter_mut().zip(rhs.iter()) {
+                        *lhs |= rhs;
+                    }
+                }
+                #[inline]
                 fn _bitxor(&self, rhs: &Self) -> Self {
                     let mut ret: #inner_type =
-                        unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                        unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let rhs = rhs.inner();
                     for (idx, lhs) in self.inner().iter().enumerate() {
                         ret[idx] = lhs ^ rhs[idx];
                     }
                     Self::new(ret)
                 }
+                #[inline]
+                fn _bitxor_assign(&mut self, rhs: &Self) {
+                    let rhs = rhs.inner();
+                    for (lhs, rhs) in self.mut_inner().iter_mut().zip(rhs.iter()) {
+                        *lhs ^= rhs;
+                    }
+                }
             )
         };
         self.defun(part);
@@ -102,7 +150,7 @@ impl HashConstructor {
             quote!(
                 #[inline]
                 fn _not(&self) -> Self {
-                    let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                    let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let inner = self.inner();
                     #({
                         let idx = #loop_unit_amount;
@@ -116,7 +164,7 @@ impl HashConstructor {
                 #[inline]
                 fn _not(&self) -> Self {
                     let mut ret: #inner_type =
-                        unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                        unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                     let inner = self.inner();
                     for (idx, val) in self.inner().iter().enumerate() {
                         ret[idx] = !val;
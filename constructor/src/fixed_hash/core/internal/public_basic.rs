@@ -193,8 +193,9 @@ impl HashConstructor {
                 self.into_inner()
             }
             /// Get a vec of a fixed hash.
+            #[cfg(feature = "alloc")]
             #[inline]
-            pub fn to_vec(&self) -> Vec<u8> {
+            pub fn to_vec(&self) -> ::alloc::vec::Vec<u8> {
                 self.inner().to_vec()
             }
             /// Get a constant raw pointer to the inner bytes array of a fixed hash.
@@ -16,29 +16,35 @@ impl HashConstructor {
     pub fn defun_pub_conv(&self) {
         self.defun_pub_conv_from_slice();
         self.defun_pub_conv_into_slice();
+        self.defun_pub_conv_ref_from_bytes();
         self.attach_error_for_conv_from_str();
         self.defun_pub_conv_from_hex_str_dict();
         self.defun_pub_conv_from_hex_str();
     }
 
     fn attach_error_for_conv_slice(&self, conv_type: &str, type_explain: &str) {
-        let error_item = utils::ident_to_ts(format!("{}Slice", conv_type).as_ref());
-        let inner_error_name = utils::ident_to_ts(format!("{}SliceError", conv_type).as_ref());
+        let error_item = format!("{}Slice", conv_type);
+        let inner_error_name = format!("{}SliceError", conv_type);
+        let inner_error_ts = utils::ident_to_ts(&inner_error_name);
         let error_explain = format!("failed to convert {} slice since {{0}}", type_explain);
         let part = quote!(
             /// Error for parse from slice.
-            #[derive(Debug, Error)]
-            pub enum #inner_error_name {
-                #[error("invalid length: {0}")]
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
+            pub enum #inner_error_ts {
                 InvalidLength(usize),
             }
+            impl ::core::fmt::Display for #inner_error_ts {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        #inner_error_ts::InvalidLength(v) => write!(f, "invalid length: {}", v),
+                    }
+                }
+            }
         );
         self.attach_common(part);
-        let part = quote!(
-            #[error(#error_explain)]
-            #error_item(#[from] #inner_error_name),
-        );
-        self.error(part);
+        self.error(&error_item, &inner_error_name, &error_explain);
     }
 
     fn defun_pub_conv_from_slice(&self) {
@@ -81,24 +87,73 @@ impl HashConstructor {
         self.defun(part);
     }
 
+    fn defun_pub_conv_ref_from_bytes(&self) {
+        let error_name = &self.ts.error_name;
+        let inner_type = &self.ts.inner_type;
+        let bytes_size = &self.ts.unit_amount;
+        let part = quote!(
+            /// Convert from a reference of fixed bytes without copying.
+            #[inline]
+            pub fn from_bytes_ref(bytes: &#inner_type) -> &Self {
+                // The struct is `#[repr(transparent)]` over the fixed bytes.
+                unsafe { &*(bytes as *const #inner_type as *const Self) }
+            }
+            /// Convert from a mutable reference of fixed bytes without copying.
+            #[inline]
+            pub fn from_bytes_mut(bytes: &mut #inner_type) -> &mut Self {
+                unsafe { &mut *(bytes as *mut #inner_type as *mut Self) }
+            }
+            /// Convert from slice without copying.
+            #[inline]
+            pub fn try_ref_from_slice(input: &[u8]) -> Result<&Self, #error_name> {
+                if input.len() != #bytes_size {
+                    Err(FromSliceError::InvalidLength(input.len()).into())
+                } else {
+                    let bytes = unsafe { &*(input.as_ptr() as *const #inner_type) };
+                    Ok(Self::from_bytes_ref(bytes))
+                }
+            }
+            /// Convert from mutable slice without copying.
+            #[inline]
+            pub fn try_mut_from_slice(input: &mut [u8]) -> Result<&mut Self, #error_name> {
+                if input.len() != #bytes_size {
+                    Err(FromSliceError::InvalidLength(input.len()).into())
+                } else {
+                    let bytes = unsafe { &mut *(input.as_mut_ptr() as *mut #inner_type) };
+                    Ok(Self::from_bytes_mut(bytes))
+                }
+            }
+        );
+        self.defun(part);
+    }
+
     fn attach_error_for_conv_from_str(&self) {
         let part = quote!(
             /// Error for parse from string.
-            #[derive(Debug, Error)]
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
             pub enum FromStrError {
-                #[error("invalid character code `{chr}` at {idx}")]
                 InvalidCharacter { chr: u8, idx: usize },
-                #[error("invalid length: {0}")]
                 InvalidLength(usize),
             }
+            impl ::core::fmt::Display for FromStrError {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        FromStrError::InvalidCharacter { chr, idx } => {
+                            write!(f, "invalid character code `{}` at {}", chr, idx)
+                        }
+                        FromStrError::InvalidLength(v) => write!(f, "invalid length: {}", v),
+                    }
+                }
+            }
         );
         self.attach_common(part);
-        #[rustfmt::skip]
-        let part = quote!(
-            #[error("failed to parse from string since {0}")]
-            FromStr(#[from] FromStrError),
+        self.error(
+            "FromStr",
+            "FromStrError",
+            "failed to parse from string since {0}",
         );
-        self.error(part);
     }
 
     fn defun_pub_conv_from_hex_str_dict(&self) {
@@ -38,6 +38,10 @@ impl HashConstructor {
 
         self.with_rand();
         self.with_heapsize();
+        self.with_bytemuck();
+        self.with_arbitrary();
+        self.with_proptest();
+        self.with_quickcheck();
         self.with_serde();
 
         self.output(ucs)
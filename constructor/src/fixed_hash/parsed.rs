@@ -25,6 +25,7 @@ impl ::std::convert::From<definition::Definition> for HashDefinition {
 
 pub struct HashAttributes {
     pub size: u64,
+    pub copy: bool,
 }
 
 impl HashAttributes {
@@ -52,7 +53,10 @@ impl HashAttributes {
 
 impl ::std::default::Default for HashAttributes {
     fn default() -> Self {
-        Self { size: 0 }
+        Self {
+            size: 0,
+            copy: false,
+        }
     }
 }
 
@@ -63,6 +67,7 @@ impl ::std::convert::From<definition::Attributes> for HashAttributes {
         for attr in input.into_iter() {
             match attr.key.to_string().as_ref() {
                 "size" => parse_attr_with_check!(Int, size, attr.value, ret, check),
+                "copy" => parse_attr_with_check!(Bool, copy, attr.value, ret, check),
                 key => panic!("Unknown attribute `{}`", key),
             }
         }
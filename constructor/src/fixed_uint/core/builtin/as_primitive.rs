@@ -129,15 +129,14 @@ impl UintConstructor {
 
     fn defun_as_prim_bytes(&self) {
         let bytes_size = &self.ts.bytes_size;
-        let inner_type = &self.ts.inner_type;
         let part = quote!(
             /// Reverses the byte order of the integer.
             #[inline]
             pub fn swap_bytes(mut self) -> Self {
                 let inner = self.mut_inner();
-                unsafe {
-                    let slice = &mut *(inner as *mut #inner_type as *mut [u8; #bytes_size]);
-                    slice.reverse()
+                inner.reverse();
+                for unit in inner.iter_mut() {
+                    *unit = unit.swap_bytes();
                 }
                 self
             }
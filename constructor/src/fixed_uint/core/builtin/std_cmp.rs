@@ -8,56 +8,127 @@
 
 //! Implement built-in traits in [`::std::cmp`].
 //!
+//! `PartialEq` and `Eq` are derived with the struct, so constants can be used in patterns.
+//!
 //! [`::std::cmp`]: https://doc.rust-lang.org/std/cmp/index.html#traits
 
 use crate::fixed_uint::UintConstructor;
 use crate::utils;
+use core::cmp;
+use proc_macro2::TokenStream;
 use quote::quote;
 
 impl UintConstructor {
     pub fn impl_traits_std_cmp(&self) {
-        self.impl_traits_std_cmp_partialeq();
-        self.impl_traits_std_cmp_eq();
         self.impl_traits_std_cmp_partialord();
         self.impl_traits_std_cmp_ord();
+        self.defun_priv_cmp_primitive_uints();
+        self.impl_traits_std_cmp_primitive_uints();
     }
 
-    fn impl_traits_std_cmp_partialeq(&self) {
-        let name = &self.ts.name;
-        let lidx = utils::pure_uint_list_to_ts(0..self.info.unit_amount);
-        let ridx = lidx.clone();
-        let part = quote!(
-            impl ::std::cmp::PartialEq for #name {
+    pub fn impl_traits_std_cmp_between(&self, uc: &Self) -> TokenStream {
+        let this_name = &self.ts.name;
+        let this_feature = &self.ts.feature;
+        let that_name = &uc.ts.name;
+        let that_feature = &uc.ts.feature;
+        let stmts = if self.info.unit_bits_size == uc.info.unit_bits_size {
+            let this_amount = self.info.unit_amount;
+            let that_amount = uc.info.unit_amount;
+            let min_amount = cmp::min(this_amount, that_amount);
+            let lhs_higher = utils::pure_uint_list_to_ts((min_amount..this_amount).rev());
+            let rhs_higher = utils::pure_uint_list_to_ts((min_amount..that_amount).rev());
+            let idx = utils::pure_uint_list_to_ts((0..min_amount).rev());
+            quote!(
+                let lhs = self.inner();
+                let rhs = other.inner();
+                #(
+                    if lhs[#lhs_higher] != 0 {
+                        return Some(::core::cmp::Ordering::Greater);
+                    }
+                )*
+                #(
+                    if rhs[#rhs_higher] != 0 {
+                        return Some(::core::cmp::Ordering::Less);
+                    }
+                )*
+                #({
+                    let idx = #idx;
+                    if lhs[idx] != rhs[idx] {
+                        return if lhs[idx] > rhs[idx] {
+                            Some(::core::cmp::Ordering::Greater)
+                        } else {
+                            Some(::core::cmp::Ordering::Less)
+                        };
+                    }
+                })*
+                Some(::core::cmp::Ordering::Equal)
+            )
+        } else {
+            // compare by the smaller unit
+            let chunk_bits_size = cmp::min(self.info.unit_bits_size, uc.info.unit_bits_size);
+            let chunk_type = utils::uint_suffix_to_ts(chunk_bits_size);
+            let this_chunks = utils::pure_uint_to_ts(self.info.bits_size / chunk_bits_size);
+            let that_chunks = utils::pure_uint_to_ts(uc.info.bits_size / chunk_bits_size);
+            let max_chunks = utils::pure_uint_to_ts(
+                cmp::max(self.info.bits_size, uc.info.bits_size) / chunk_bits_size,
+            );
+            let chunk = |unit_bits_size: u64, inner: TokenStream| {
+                if unit_bits_size == chunk_bits_size {
+                    quote!(#inner[idx])
+                } else {
+                    let ratio = utils::pure_uint_to_ts(unit_bits_size / chunk_bits_size);
+                    let chunk_bits_size = utils::pure_uint_to_ts(chunk_bits_size);
+                    quote!(
+                        (#inner[idx / #ratio] >> ((idx % #ratio) * #chunk_bits_size))
+                            as #chunk_type
+                    )
+                }
+            };
+            let this_chunk = chunk(self.info.unit_bits_size, quote!(lhs));
+            let that_chunk = chunk(uc.info.unit_bits_size, quote!(rhs));
+            quote!(
+                let lhs = self.inner();
+                let rhs = other.inner();
+                let mut idx = #max_chunks;
+                while idx > 0 {
+                    idx -= 1;
+                    let l = if idx < #this_chunks { #this_chunk } else { 0 };
+                    let r = if idx < #that_chunks { #that_chunk } else { 0 };
+                    if l != r {
+                        return if l > r {
+                            Some(::core::cmp::Ordering::Greater)
+                        } else {
+                            Some(::core::cmp::Ordering::Less)
+                        };
+                    }
+                }
+                Some(::core::cmp::Ordering::Equal)
+            )
+        };
+        quote!(
+            #[cfg(all(feature = #this_feature, feature = #that_feature))]
+            impl ::core::cmp::PartialEq<#that_name> for #this_name {
                 #[inline]
-                fn eq(&self, other: &Self) -> bool {
-                    let lhs = self.inner();
-                    let rhs = other.inner();
-                    #(
-                        if lhs[#lidx] != rhs[#ridx] {
-                            return false;
-                        }
-                    )*
-                    true
+                fn eq(&self, other: &#that_name) -> bool {
+                    self.partial_cmp(other) == Some(::core::cmp::Ordering::Equal)
                 }
             }
-        );
-        self.implt(part);
-    }
-
-    fn impl_traits_std_cmp_eq(&self) {
-        let name = &self.ts.name;
-        let part = quote!(
-            impl ::std::cmp::Eq for #name {}
-        );
-        self.implt(part);
+            #[cfg(all(feature = #this_feature, feature = #that_feature))]
+            impl ::core::cmp::PartialOrd<#that_name> for #this_name {
+                #[inline]
+                fn partial_cmp(&self, other: &#that_name) -> Option<::core::cmp::Ordering> {
+                    #stmts
+                }
+            }
+        )
     }
 
     fn impl_traits_std_cmp_partialord(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::cmp::PartialOrd for #name {
+            impl ::core::cmp::PartialOrd for #name {
                 #[inline]
-                fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
                     Some(self.cmp(other))
                 }
             }
@@ -69,25 +140,105 @@ impl UintConstructor {
         let name = &self.ts.name;
         let idx = utils::pure_uint_list_to_ts((0..self.info.unit_amount).rev());
         let part = quote!(
-            impl ::std::cmp::Ord for #name {
+            impl ::core::cmp::Ord for #name {
                 #[inline]
-                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
                     let lhs = self.inner();
                     let rhs = other.inner();
                     #({
                         let idx = #idx;
                         if lhs[idx] != rhs[idx] {
                             return if lhs[idx] > rhs[idx] {
-                                ::std::cmp::Ordering::Greater
+                                ::core::cmp::Ordering::Greater
                             } else {
-                                ::std::cmp::Ordering::Less
+                                ::core::cmp::Ordering::Less
                             };
                         }
                     })*
-                    ::std::cmp::Ordering::Equal
+                    ::core::cmp::Ordering::Equal
                 }
             }
         );
         self.implt(part);
     }
+
+    fn defun_priv_cmp_primitive_uints(&self) {
+        let unit_suffix = &self.ts.unit_suffix;
+        for prim_bits_size in &[8u64, 16, 32, 64, 128] {
+            let prim_type = utils::uint_suffix_to_ts(*prim_bits_size);
+            let func_name = utils::ident_to_ts(format!("_cmp_u{}", prim_bits_size).as_ref());
+            let (times, stmt) = if *prim_bits_size <= self.info.unit_bits_size {
+                (1, quote!(inner[0].cmp(&(other as #unit_suffix))))
+            } else {
+                let into_func = utils::ident_to_ts(format!("_into_u{}", prim_bits_size).as_ref());
+                let times = cmp::min(
+                    prim_bits_size / self.info.unit_bits_size,
+                    self.info.unit_amount,
+                );
+                (times, quote!(self.#into_func().cmp(&other)))
+            };
+            let loop_higher = utils::pure_uint_list_to_ts(times..self.info.unit_amount);
+            let part = quote!(
+                #[inline]
+                fn #func_name(&self, other: #prim_type) -> ::core::cmp::Ordering {
+                    let inner = self.inner();
+                    #(
+                        if inner[#loop_higher] != 0 {
+                            return ::core::cmp::Ordering::Greater;
+                        }
+                    )*
+                    #stmt
+                }
+            );
+            self.defun(part);
+        }
+    }
+
+    fn impl_traits_std_cmp_primitive_uints(&self) {
+        let name = &self.ts.name;
+        let prims = [
+            ("u8", "_cmp_u8"),
+            ("u16", "_cmp_u16"),
+            ("u32", "_cmp_u32"),
+            ("u64", "_cmp_u64"),
+            ("u128", "_cmp_u128"),
+            ("usize", "_cmp_u128"),
+        ];
+        for (prim, func) in &prims[..] {
+            let prim_type = utils::ident_to_ts(prim);
+            let func_name = utils::ident_to_ts(func);
+            let (this_val, that_val) = if *prim == "usize" {
+                (quote!(*self as u128), quote!(*other as u128))
+            } else {
+                (quote!(*self), quote!(*other))
+            };
+            let part = quote!(
+                impl ::core::cmp::PartialEq<#prim_type> for #name {
+                    #[inline]
+                    fn eq(&self, other: &#prim_type) -> bool {
+                        self.#func_name(#that_val) == ::core::cmp::Ordering::Equal
+                    }
+                }
+                impl ::core::cmp::PartialOrd<#prim_type> for #name {
+                    #[inline]
+                    fn partial_cmp(&self, other: &#prim_type) -> Option<::core::cmp::Ordering> {
+                        Some(self.#func_name(#that_val))
+                    }
+                }
+                impl ::core::cmp::PartialEq<#name> for #prim_type {
+                    #[inline]
+                    fn eq(&self, other: &#name) -> bool {
+                        other.#func_name(#this_val) == ::core::cmp::Ordering::Equal
+                    }
+                }
+                impl ::core::cmp::PartialOrd<#name> for #prim_type {
+                    #[inline]
+                    fn partial_cmp(&self, other: &#name) -> Option<::core::cmp::Ordering> {
+                        Some(other.#func_name(#this_val).reverse())
+                    }
+                }
+            );
+            self.implt(part);
+        }
+    }
 }
@@ -20,12 +20,15 @@ impl UintConstructor {
     pub fn impl_traits_std_convert(&self) {
         self.impl_traits_std_convert_from_bool();
         self.impl_traits_std_convert_from_primitive_uint();
+        self.attach_error_for_conv_primitive();
+        self.impl_traits_std_convert_try_from_primitive_int();
+        self.impl_traits_std_convert_try_into_primitive();
     }
 
     fn impl_traits_std_convert_from_bool(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::convert::From<bool> for #name {
+            impl ::core::convert::From<bool> for #name {
                 #[inline]
                 fn from(val: bool) -> Self {
                     if val {
@@ -45,13 +48,13 @@ impl UintConstructor {
             let prim_type = utils::uint_suffix_to_ts(*prim_bits_size);
             let func_name = utils::ident_to_ts(format!("_from_u{}", prim_bits_size).as_ref());
             let part = quote!(
-                impl ::std::convert::From<#prim_type> for #name {
+                impl ::core::convert::From<#prim_type> for #name {
                     #[inline]
                     fn from(prim: #prim_type) -> Self {
                         Self::#func_name(prim)
                     }
                 }
-                impl<'a> ::std::convert::From<&'a #prim_type> for #name {
+                impl<'a> ::core::convert::From<&'a #prim_type> for #name {
                     #[inline]
                     fn from(prim: &#prim_type) -> Self {
                         Self::#func_name(*prim)
@@ -61,4 +64,163 @@ impl UintConstructor {
             self.implt(part);
         }
     }
+
+    fn attach_error_for_conv_primitive(&self) {
+        let part = quote!(
+            /// Error for convert from primitive integers.
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
+            pub enum FromPrimitiveError {
+                Negative(i128),
+            }
+            impl ::core::fmt::Display for FromPrimitiveError {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        FromPrimitiveError::Negative(v) => write!(f, "negative value: {}", v),
+                    }
+                }
+            }
+            /// Error for convert into primitive integers.
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
+            pub enum IntoPrimitiveError {
+                Overflow(usize),
+            }
+            impl ::core::fmt::Display for IntoPrimitiveError {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        IntoPrimitiveError::Overflow(v) => {
+                            write!(f, "value is too big (highest one is at {})", v)
+                        }
+                    }
+                }
+            }
+        );
+        self.attach_common(part);
+        self.error(
+            "FromPrimitive",
+            "FromPrimitiveError",
+            "failed to convert from primitive since {0}",
+        );
+        self.error(
+            "IntoPrimitive",
+            "IntoPrimitiveError",
+            "failed to convert into primitive since {0}",
+        );
+    }
+
+    fn impl_traits_std_convert_try_from_primitive_int(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let prims = [
+            ("i8", "u8", "_from_u8"),
+            ("i16", "u16", "_from_u16"),
+            ("i32", "u32", "_from_u32"),
+            ("i64", "u64", "_from_u64"),
+            ("i128", "u128", "_from_u128"),
+            ("isize", "u128", "_from_u128"),
+        ];
+        for (prim, uint, func) in &prims[..] {
+            let prim_type = utils::ident_to_ts(prim);
+            let uint_type = utils::ident_to_ts(uint);
+            let func_name = utils::ident_to_ts(func);
+            let part = quote!(
+                impl ::core::convert::TryFrom<#prim_type> for #name {
+                    type Error = #error_name;
+                    #[inline]
+                    fn try_from(prim: #prim_type) -> Result<Self, Self::Error> {
+                        if prim < 0 {
+                            Err(FromPrimitiveError::Negative(prim as i128).into())
+                        } else {
+                            Ok(Self::#func_name(prim as #uint_type))
+                        }
+                    }
+                }
+                impl<'a> ::core::convert::TryFrom<&'a #prim_type> for #name {
+                    type Error = #error_name;
+                    #[inline]
+                    fn try_from(prim: &#prim_type) -> Result<Self, Self::Error> {
+                        <Self as ::core::convert::TryFrom<#prim_type>>::try_from(*prim)
+                    }
+                }
+            );
+            self.implt(part);
+        }
+    }
+
+    fn impl_traits_std_convert_try_into_primitive(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let prims = [
+            ("u8", 8, "_into_u8"),
+            ("u16", 16, "_into_u16"),
+            ("u32", 32, "_into_u32"),
+            ("u64", 64, "_into_u64"),
+            ("u128", 128, "_into_u128"),
+            ("i8", 7, "_into_u8"),
+            ("i16", 15, "_into_u16"),
+            ("i32", 31, "_into_u32"),
+            ("i64", 63, "_into_u64"),
+            ("i128", 127, "_into_u128"),
+        ];
+        for (prim, prim_bits_size, func) in &prims[..] {
+            let prim_type = utils::ident_to_ts(prim);
+            let func_name = utils::ident_to_ts(func);
+            let prim_bits_size = utils::pure_uint_to_ts(*prim_bits_size);
+            let part = quote!(
+                impl<'a> ::core::convert::TryFrom<&'a #name> for #prim_type {
+                    type Error = #error_name;
+                    #[inline]
+                    fn try_from(value: &#name) -> Result<Self, Self::Error> {
+                        match value.highest_one() {
+                            Some(idx) if idx >= #prim_bits_size => {
+                                Err(IntoPrimitiveError::Overflow(idx).into())
+                            }
+                            _ => Ok(value.#func_name() as #prim_type),
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<#name> for #prim_type {
+                    type Error = #error_name;
+                    #[inline]
+                    fn try_from(value: #name) -> Result<Self, Self::Error> {
+                        <Self as ::core::convert::TryFrom<&#name>>::try_from(&value)
+                    }
+                }
+            );
+            self.implt(part);
+        }
+        // the sizes of pointer-sized integers depend on the target
+        let prims = [
+            ("usize", quote!(::core::mem::size_of::<usize>() * 8)),
+            ("isize", quote!(::core::mem::size_of::<isize>() * 8 - 1)),
+        ];
+        for (prim, prim_bits_size) in &prims[..] {
+            let prim_type = utils::ident_to_ts(prim);
+            let part = quote!(
+                impl<'a> ::core::convert::TryFrom<&'a #name> for #prim_type {
+                    type Error = #error_name;
+                    #[inline]
+                    fn try_from(value: &#name) -> Result<Self, Self::Error> {
+                        match value.highest_one() {
+                            Some(idx) if idx >= #prim_bits_size => {
+                                Err(IntoPrimitiveError::Overflow(idx).into())
+                            }
+                            _ => Ok(value._into_u128() as #prim_type),
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<#name> for #prim_type {
+                    type Error = #error_name;
+                    #[inline]
+                    fn try_from(value: #name) -> Result<Self, Self::Error> {
+                        <Self as ::core::convert::TryFrom<&#name>>::try_from(&value)
+                    }
+                }
+            );
+            self.implt(part);
+        }
+    }
 }
@@ -17,7 +17,7 @@ impl UintConstructor {
     pub fn impl_traits_std_default(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::default::Default for #name {
+            impl ::core::default::Default for #name {
                 #[inline]
                 fn default() -> Self {
                     Self::zero()
@@ -16,12 +16,17 @@ use quote::quote;
 
 impl UintConstructor {
     pub fn impl_traits_std_fmt(&self) {
+        self.defun_priv_fmt_radix();
         self.impl_traits_std_fmt_debug();
         self.impl_traits_std_fmt_binary();
         self.impl_traits_std_fmt_octal();
         self.impl_traits_std_fmt_lowerhex();
         self.impl_traits_std_fmt_upperhex();
         self.impl_traits_std_fmt_display();
+        self.util_fmt_padded();
+        self.defun_priv_fmt_exp();
+        self.impl_traits_std_fmt_lowerexp();
+        self.impl_traits_std_fmt_upperexp();
     }
 
     pub fn impl_traits_std_fmt_debug(&self) {
@@ -32,10 +37,19 @@ impl UintConstructor {
         let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let loop_unit_amount_skip_first = &utils::pure_uint_list_to_ts(1..self.info.unit_amount);
         let part = quote!(
-            impl ::std::fmt::Debug for #name {
+            /// Show the inner units of the number, the least significant unit comes first.
+            #[inline]
+            pub fn debug_limbs(&self) -> impl ::core::fmt::Debug + '_ {
+                DebugLimbs(self)
+            }
+        );
+        self.defun(part);
+        let part = quote!(
+            struct DebugLimbs<'a>(&'a #name);
+            impl<'a> ::core::fmt::Debug for DebugLimbs<'a> {
                 #[inline]
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    let data = self.inner();
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let data = self.0.inner();
                     let alternate = f.alternate();
                     write!(f, stringify!(#name))?;
                     write!(f, " ( [")?;
@@ -54,84 +68,62 @@ impl UintConstructor {
                     }
                 }
             }
+            impl ::core::fmt::Debug for #name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    if f.alternate() {
+                        ::core::fmt::Debug::fmt(&self.debug_limbs(), f)
+                    } else {
+                        write!(f, "{} ({:#x})", self, self)
+                    }
+                }
+            }
         );
         self.implt(part);
     }
 
-    fn impl_traits_std_fmt_base_2pow2n(
-        &self,
-        trait_name: &str,
-        prefix_char: char,
-        format_char: char,
-        exp: u64,
-    ) {
-        let name = &self.ts.name;
-        let unit_amount = &self.ts.unit_amount;
-        let trait_name = utils::ident_to_ts(trait_name);
-        let prefix = format!("0{}", prefix_char);
-        let write_tpl = format!("{{:{}}}", format_char);
-        let write_tpl_padded = format!("{{:0width${}}}", format_char);
-        let width = self.info.unit_bits_size / exp;
-        let width = utils::pure_uint_to_ts(width);
+    fn defun_priv_fmt_radix(&self) {
+        let bits_size = &self.ts.bits_size;
         let part = quote!(
-            impl ::std::fmt::#trait_name for #name {
-                #[inline]
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    let data = self.inner();
-                    if f.alternate() {
-                        write!(f, #prefix)?;
-                    }
-                    let mut idx = #unit_amount - 1;
-                    while idx > 0 {
-                        if data[idx] == 0 {
-                            idx -= 1;
-                            continue;
-                        }
-                        break;
-                    }
-                    if idx == 0 {
-                        write!(f, #write_tpl, data[0])
+            /// Format the number in the given radix, and pad it as a primitive integer.
+            #[inline]
+            fn _fmt_radix(
+                &self,
+                f: &mut ::core::fmt::Formatter,
+                radix: u32,
+                upper: bool,
+                prefix: &str,
+            ) -> ::core::fmt::Result {
+                let mut buf = [0u8; #bits_size];
+                let len = self._write_digits_le(&mut buf, radix);
+                let digits = &mut buf[..len];
+                digits.reverse();
+                for digit in digits.iter_mut() {
+                    *digit = if *digit < 10 {
+                        b'0' + *digit
+                    } else if upper {
+                        b'A' + *digit - 10
                     } else {
-                        write!(f, #write_tpl, data[idx])?;
-                        idx -= 1;
-                        while idx > 0 {
-                            write!(f, #write_tpl_padded, data[idx], width = #width)?;
-                            idx -= 1;
-                        }
-                        write!(f, #write_tpl_padded, data[0], width = #width)
-                    }
+                        b'a' + *digit - 10
+                    };
                 }
+                // all digits are ASCII characters
+                let digits = ::core::str::from_utf8(digits).unwrap();
+                f.pad_integral(true, prefix, digits)
             }
         );
-        self.implt(part);
+        self.defun(part);
     }
 
-    fn impl_traits_std_fmt_base_lt10(&self, trait_name: &str, prefix: &str, num: u64) {
+    fn impl_traits_std_fmt_radix(&self, trait_name: &str, prefix: &str, radix: u32, upper: bool) {
         let name = &self.ts.name;
         let trait_name = utils::ident_to_ts(trait_name);
-        if num == 0 {
-            unreachable!();
-        }
-        let num = utils::pure_uint_to_ts(num);
+        let radix = utils::pure_uint_to_ts(u64::from(radix));
         let part = quote!(
-            impl ::std::fmt::#trait_name for #name {
+            impl ::core::fmt::#trait_name for #name {
                 #[inline]
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    if f.alternate() {
-                        write!(f, #prefix)?;
-                    }
-                    let mut v = Vec::new();
-                    let (mut q, r) = self._div_unit_with_rem(#num);
-                    v.push(r);
-                    while !q.is_zero() {
-                        let (q_new, r) = q._div_unit_with_rem(#num);
-                        v.push(r);
-                        q = q_new;
-                    }
-                    for n in v.iter().rev() {
-                        write!(f, "{}", n)?;
-                    }
-                    write!(f, "")
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    self._fmt_radix(f, #radix, #upper, #prefix)
                 }
             }
         );
@@ -139,22 +131,133 @@ impl UintConstructor {
     }
 
     pub fn impl_traits_std_fmt_binary(&self) {
-        self.impl_traits_std_fmt_base_2pow2n("Binary", 'b', 'b', 1);
+        self.impl_traits_std_fmt_radix("Binary", "0b", 2, false);
     }
 
     pub fn impl_traits_std_fmt_octal(&self) {
-        self.impl_traits_std_fmt_base_lt10("Octal", "0o", 8);
+        self.impl_traits_std_fmt_radix("Octal", "0o", 8, false);
     }
 
     pub fn impl_traits_std_fmt_lowerhex(&self) {
-        self.impl_traits_std_fmt_base_2pow2n("LowerHex", 'x', 'x', 4);
+        self.impl_traits_std_fmt_radix("LowerHex", "0x", 16, false);
     }
 
     pub fn impl_traits_std_fmt_upperhex(&self) {
-        self.impl_traits_std_fmt_base_2pow2n("UpperHex", 'x', 'X', 4);
+        self.impl_traits_std_fmt_radix("UpperHex", "0x", 16, true);
     }
 
     pub fn impl_traits_std_fmt_display(&self) {
-        self.impl_traits_std_fmt_base_lt10("Display", "", 10);
+        self.impl_traits_std_fmt_radix("Display", "", 10, false);
+    }
+
+    fn util_fmt_padded(&self) {
+        self.util(utils::fmt_padded_ts());
+    }
+
+    fn defun_priv_fmt_exp(&self) {
+        let bits_size = &self.ts.bits_size;
+        let utils_name = &self.ts.utils_name;
+        let part = quote!(
+            /// Format the number in scientific notation, the precision is the amount of the
+            /// fractional digits, which are rounded half to even.
+            #[inline]
+            fn _fmt_exp(&self, f: &mut ::core::fmt::Formatter, upper: bool) -> ::core::fmt::Result {
+                use core::fmt::Write;
+                let mut buf = [0u8; #bits_size];
+                let len = self._write_digits_le(&mut buf, 10);
+                let digits = &mut buf[..len];
+                digits.reverse();
+                let mut exp = len - 1;
+                let (keep, zeros) = if let Some(prec) = f.precision() {
+                    if prec + 1 < len {
+                        let keep = prec + 1;
+                        let round_up = match digits[keep] {
+                            x if x > 5 => true,
+                            x if x < 5 => false,
+                            _ => {
+                                digits[(keep + 1)..].iter().any(|x| *x != 0)
+                                    || digits[keep - 1] % 2 == 1
+                            }
+                        };
+                        if round_up {
+                            let mut idx = keep;
+                            loop {
+                                if idx == 0 {
+                                    // all kept digits are nines
+                                    digits[0] = 1;
+                                    exp += 1;
+                                    break;
+                                }
+                                idx -= 1;
+                                if digits[idx] == 9 {
+                                    digits[idx] = 0;
+                                } else {
+                                    digits[idx] += 1;
+                                    break;
+                                }
+                            }
+                        }
+                        (keep, 0)
+                    } else {
+                        (len, prec + 1 - len)
+                    }
+                } else {
+                    let keep = digits
+                        .iter()
+                        .rposition(|x| *x != 0)
+                        .map(|x| x + 1)
+                        .unwrap_or(1);
+                    (keep, 0)
+                };
+                for digit in digits[..keep].iter_mut() {
+                    *digit += b'0';
+                }
+                let mantissa = ::core::str::from_utf8(&digits[..keep]).unwrap();
+                let has_point = keep > 1 || zeros > 0;
+                let mut exp_len = 1;
+                let mut exp_tmp = exp;
+                while exp_tmp >= 10 {
+                    exp_tmp /= 10;
+                    exp_len += 1;
+                }
+                let sign = if f.sign_plus() { "+" } else { "" };
+                let body_len = keep + (has_point as usize) + zeros + 1 + exp_len;
+                #utils_name::fmt_padded(f, sign, body_len, |f| {
+                    f.write_str(&mantissa[..1])?;
+                    if has_point {
+                        f.write_char('.')?;
+                        f.write_str(&mantissa[1..])?;
+                        for _ in 0..zeros {
+                            f.write_char('0')?;
+                        }
+                    }
+                    f.write_char(if upper { 'E' } else { 'e' })?;
+                    write!(f, "{}", exp)
+                })
+            }
+        );
+        self.defun(part);
+    }
+
+    fn impl_traits_std_fmt_exp(&self, trait_name: &str, upper: bool) {
+        let name = &self.ts.name;
+        let trait_name = utils::ident_to_ts(trait_name);
+        let part = quote!(
+            impl ::core::fmt::#trait_name for #name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    self._fmt_exp(f, #upper)
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    pub fn impl_traits_std_fmt_lowerexp(&self) {
+        self.impl_traits_std_fmt_exp("LowerExp", false);
+    }
+
+    pub fn impl_traits_std_fmt_upperexp(&self) {
+        self.impl_traits_std_fmt_exp("UpperExp", true);
     }
 }
@@ -17,10 +17,10 @@ impl UintConstructor {
     pub fn impl_traits_std_hash(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl ::std::hash::Hash for #name {
+            impl ::core::hash::Hash for #name {
                 #[inline]
-                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
-                    ::std::hash::Hash::hash(&self.inner()[..], state)
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&self.inner()[..], state)
                 }
             }
         );
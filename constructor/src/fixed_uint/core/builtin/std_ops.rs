@@ -37,6 +37,7 @@ impl UintConstructor {
         let trait_name = utils::ident_to_ts(trait_name_str);
         let func_name = utils::ident_to_ts(func_name);
         let real_func = utils::ident_to_ts(realfunc);
+        let real_func_assign = utils::ident_to_ts(format!("{}_assign", realfunc).as_ref());
         let trait_assign_name = utils::ident_to_ts(format!("{}Assign", trait_name).as_ref());
         let func_assign_name = utils::ident_to_ts(format!("{}_assign", func_name).as_ref());
         let panic_stmt = match trait_name_str {
@@ -50,9 +51,9 @@ impl UintConstructor {
             _ => unreachable!(),
         };
         let part = quote!(
-            impl<'a, Rhs> ::std::ops::#trait_name<Rhs> for &'a #name
+            impl<'a, Rhs> ::core::ops::#trait_name<Rhs> for &'a #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 type Output = #name;
                 #[inline]
@@ -64,23 +65,22 @@ impl UintConstructor {
                     ret
                 }
             }
-            impl<Rhs> ::std::ops::#trait_name<Rhs> for #name
+            impl<Rhs> ::core::ops::#trait_name<Rhs> for #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 type Output = #name;
                 #[inline]
-                fn #func_name(self, other: Rhs) -> Self::Output {
-                    let (ret, of) = self.#real_func(&other.into());
-                    if of {
+                fn #func_name(mut self, other: Rhs) -> Self::Output {
+                    if self.#real_func_assign(&other.into()) {
                         #panic_stmt
                     }
-                    ret
+                    self
                 }
             }
-            impl<Rhs> ::std::ops::#trait_assign_name<Rhs> for #name
+            impl<Rhs> ::core::ops::#trait_assign_name<Rhs> for #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 #[inline]
                 fn #func_assign_name(&mut self, other: Rhs) {
@@ -91,7 +91,7 @@ impl UintConstructor {
                     *self = ret;
                 }
             }
-            impl<'a, 'b> ::std::ops::#trait_name<&'b #name> for &'a #name {
+            impl<'a, 'b> ::core::ops::#trait_name<&'b #name> for &'a #name {
                 type Output = #name;
                 #[inline]
                 fn #func_name(self, other: &#name) -> Self::Output {
@@ -102,18 +102,17 @@ impl UintConstructor {
                     ret
                 }
             }
-            impl<'a> ::std::ops::#trait_name<&'a #name> for #name {
+            impl<'a> ::core::ops::#trait_name<&'a #name> for #name {
                 type Output = #name;
                 #[inline]
-                fn #func_name(self, other: &'a #name) -> Self::Output {
-                    let (ret, of) = self.#real_func(other);
-                    if of {
+                fn #func_name(mut self, other: &'a #name) -> Self::Output {
+                    if self.#real_func_assign(other) {
                         #panic_stmt
                     }
-                    ret
+                    self
                 }
             }
-            impl<'a> ::std::ops::#trait_assign_name<&'a #name> for #name {
+            impl<'a> ::core::ops::#trait_assign_name<&'a #name> for #name {
                 #[inline]
                 fn #func_assign_name(&mut self, other: &#name) {
                     let (ret, of) = self.#real_func(other);
@@ -133,12 +132,13 @@ impl UintConstructor {
         let trait_name = utils::ident_to_ts(trait_name);
         let func_name = utils::ident_to_ts(func_name);
         let real_func = utils::ident_to_ts(realfunc);
+        let real_func_assign = utils::ident_to_ts(format!("{}_assign", realfunc).as_ref());
         let trait_assign_name = utils::ident_to_ts(format!("{}Assign", trait_name).as_ref());
         let func_assign_name = utils::ident_to_ts(format!("{}_assign", func_name).as_ref());
         let part = quote!(
-            impl<'a, Rhs> ::std::ops::#trait_name<Rhs> for &'a #name
+            impl<'a, Rhs> ::core::ops::#trait_name<Rhs> for &'a #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 type Output = #name;
                 #[inline]
@@ -146,43 +146,45 @@ impl UintConstructor {
                     self.#real_func(&other.into())
                 }
             }
-            impl<Rhs> ::std::ops::#trait_name<Rhs> for #name
+            impl<Rhs> ::core::ops::#trait_name<Rhs> for #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 type Output = #name;
                 #[inline]
-                fn #func_name(self, other: Rhs) -> Self::Output {
-                    self.#real_func(&other.into())
+                fn #func_name(mut self, other: Rhs) -> Self::Output {
+                    self.#real_func_assign(&other.into());
+                    self
                 }
             }
-            impl<Rhs> ::std::ops::#trait_assign_name<Rhs> for #name
+            impl<Rhs> ::core::ops::#trait_assign_name<Rhs> for #name
             where
-                Rhs: ::std::convert::Into<#name>,
+                Rhs: ::core::convert::Into<#name>,
             {
                 #[inline]
                 fn #func_assign_name(&mut self, other: Rhs) {
-                    *self = self.#real_func(&other.into());
+                    self.#real_func_assign(&other.into());
                 }
             }
-            impl<'a, 'b> ::std::ops::#trait_name<&'b #name> for &'a #name {
+            impl<'a, 'b> ::core::ops::#trait_name<&'b #name> for &'a #name {
                 type Output = #name;
                 #[inline]
                 fn #func_name(self, other: &#name) -> Self::Output {
                     self.#real_func(other)
                 }
             }
-            impl<'a> ::std::ops::#trait_name<&'a #name> for #name {
+            impl<'a> ::core::ops::#trait_name<&'a #name> for #name {
                 type Output = #name;
                 #[inline]
-                fn #func_name(self, other: &#name) -> Self::Output {
-                    self.#real_func(other)
+                fn #func_name(mut self, other: &#name) -> Self::Output {
+                    self.#real_func_assign(other);
+                    self
                 }
             }
-            impl<'a> ::std::ops::#trait_assign_name<&'a #name> for #name {
+            impl<'a> ::core::ops::#trait_assign_name<&'a #name> for #name {
                 #[inline]
                 fn #func_assign_name(&mut self, other: &#name) {
-                    *self = self.#real_func(other);
+                    self.#real_func_assign(other);
                 }
             }
         );
@@ -193,14 +195,14 @@ impl UintConstructor {
     fn impl_traits_std_ops_not(&self) {
         let name = &self.ts.name;
         let part = quote!(
-            impl<'a> ::std::ops::Not for &'a #name {
+            impl<'a> ::core::ops::Not for &'a #name {
                 type Output = #name;
                 #[inline]
                 fn not(self) -> Self::Output {
                     self._not()
                 }
             }
-            impl ::std::ops::Not for #name {
+            impl ::core::ops::Not for #name {
                 type Output = #name;
                 #[inline]
                 fn not(self) -> Self::Output {
@@ -221,42 +223,42 @@ impl UintConstructor {
             let uint_name = utils::ident_to_ts(uint_name);
             let real_func = utils::ident_to_ts(format!("_ush{}", direction).as_ref());
             let part = quote!(
-                impl<'a, 'b> ::std::ops::#trait_name<&'a #uint_name> for &'b #name {
+                impl<'a, 'b> ::core::ops::#trait_name<&'a #uint_name> for &'b #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#uint_name) -> Self::Output {
                         self.#real_func(*other as u128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<#uint_name> for &'a #name {
+                impl<'a> ::core::ops::#trait_name<#uint_name> for &'a #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #uint_name) -> Self::Output {
                         self.#real_func(other as u128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<&'a #uint_name> for #name {
+                impl<'a> ::core::ops::#trait_name<&'a #uint_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#uint_name) -> Self::Output {
                         self.#real_func(*other as u128)
                     }
                 }
-                impl ::std::ops::#trait_name<#uint_name> for #name {
+                impl ::core::ops::#trait_name<#uint_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #uint_name) -> Self::Output {
                         self.#real_func(other as u128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_assign_name<&'a #uint_name> for #name {
+                impl<'a> ::core::ops::#trait_assign_name<&'a #uint_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: &#uint_name) {
                         let ret = self.#real_func(*other as u128);
                         *self = ret;
                     }
                 }
-                impl ::std::ops::#trait_assign_name<#uint_name> for #name {
+                impl ::core::ops::#trait_assign_name<#uint_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: #uint_name) {
                         let ret = self.#real_func(other as u128);
@@ -270,42 +272,42 @@ impl UintConstructor {
             let int_name = utils::ident_to_ts(int_name);
             let real_func = utils::ident_to_ts(format!("_ish{}", direction).as_ref());
             let part = quote!(
-                impl<'a, 'b> ::std::ops::#trait_name<&'a #int_name> for &'b #name {
+                impl<'a, 'b> ::core::ops::#trait_name<&'a #int_name> for &'b #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#int_name) -> Self::Output {
                         self.#real_func(*other as i128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<#int_name> for &'a #name {
+                impl<'a> ::core::ops::#trait_name<#int_name> for &'a #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #int_name) -> Self::Output {
                         self.#real_func(other as i128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_name<&'a #int_name> for #name {
+                impl<'a> ::core::ops::#trait_name<&'a #int_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: &#int_name) -> Self::Output {
                         self.#real_func(*other as i128)
                     }
                 }
-                impl ::std::ops::#trait_name<#int_name> for #name {
+                impl ::core::ops::#trait_name<#int_name> for #name {
                     type Output = #name;
                     #[inline]
                     fn #func_name(self, other: #int_name) -> Self::Output {
                         self.#real_func(other as i128)
                     }
                 }
-                impl<'a> ::std::ops::#trait_assign_name<&'a #int_name> for #name {
+                impl<'a> ::core::ops::#trait_assign_name<&'a #int_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: &#int_name) {
                         let ret = self.#real_func(*other as i128);
                         *self = ret;
                     }
                 }
-                impl ::std::ops::#trait_assign_name<#int_name> for #name {
+                impl ::core::ops::#trait_assign_name<#int_name> for #name {
                     #[inline]
                     fn #func_assign_name(&mut self, other: #int_name) {
                         let ret = self.#real_func(other as i128);
@@ -8,7 +8,52 @@
 
 //! Implement built-in traits in [`::std::str`].
 //!
-//! Not implement `FromStr` traits to reduce confusion.
-//! Use `from_bin_str`, `from_oct_str`, `from_hex_str` or `from_dec_str` to instead of.
+//! The `FromStr` trait follows the same rules as the constructor macros:
+//! any number of `_` could be used to separate the digits, and the radix is detected by the
+//! prefix (`0b`, `0o`, `0x` or none for decimal).
 //!
 //! [`::std::str`]: https://doc.rust-lang.org/std/str/index.html#traits
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn impl_traits_std_str(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let bits_size = &self.ts.bits_size;
+        let part = quote!(
+            impl ::core::str::FromStr for #name {
+                type Err = #error_name;
+                /// Convert from a string with an optional prefix for the radix.
+                #[inline]
+                fn from_str(input: &str) -> Result<Self, Self::Err> {
+                    // the longest valid input is a binary string with a prefix
+                    let mut buf = [0u8; #bits_size + 2];
+                    let mut len = 0;
+                    for chr in input.bytes().filter(|chr| *chr != b'_') {
+                        if len == buf.len() {
+                            return Err(FromStrError::InvalidLength(input.len()).into());
+                        }
+                        buf[len] = chr;
+                        len += 1;
+                    }
+                    // only ASCII characters are removed, so it's still a valid UTF-8 string
+                    let input = ::core::str::from_utf8(&buf[..len]).unwrap();
+                    if input.len() < 3 {
+                        Self::from_dec_str(input)
+                    } else {
+                        // compare bytes, the second character might not be an ASCII character
+                        match &input.as_bytes()[..2] {
+                            b"0b" => Self::from_bin_str(&input[2..]),
+                            b"0o" => Self::from_oct_str(&input[2..]),
+                            b"0x" => Self::from_hex_str(&input[2..]),
+                            _ => Self::from_dec_str(input),
+                        }
+                    }
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -22,6 +22,7 @@ pub struct UintInformation {
     pub unit_bits_size: u64,
     pub unit_bytes_size: u64,
     pub unit_amount: u64,
+    pub copy: bool,
 }
 
 impl ::std::convert::From<parsed::UintDefinition> for UintInformation {
@@ -37,6 +38,8 @@ impl ::std::convert::From<parsed::UintDefinition> for UintInformation {
         let unit_bytes_size = attrs.unit_size / 8;
         // how many units in an unsigned integer
         let unit_amount = attrs.size / attrs.unit_size;
+        // derive `Copy` or not
+        let copy = attrs.copy;
         Self {
             name,
             bits_size,
@@ -44,6 +47,7 @@ impl ::std::convert::From<parsed::UintDefinition> for UintInformation {
             unit_bits_size,
             unit_bytes_size,
             unit_amount,
+            copy,
         }
     }
 }
@@ -123,7 +127,7 @@ pub struct UintConstructor {
     // Outputs (once)
     common: Cell<Vec<TokenStream>>,
     // Outputs (errors)
-    errors: Cell<Vec<TokenStream>>,
+    errors: Cell<Vec<(TokenStream, TokenStream)>>,
     // Outputs (utils)
     utils: Cell<Vec<TokenStream>>,
     // Outputs (traits)
@@ -158,10 +162,23 @@ impl UintConstructor {
         let name = &self.ts.name;
         let feature = &self.ts.feature;
         let inner_type = &self.ts.inner_type;
+        let derives = if self.info.copy {
+            quote!(Clone, Copy, PartialEq, Eq)
+        } else {
+            quote!(Clone, PartialEq, Eq)
+        };
         let part = quote!(
             /// Fixed non-negative integer type.
+            ///
+            /// The layout is the same as the inner array: the units are in little-endian order
+            /// (the lowest unit first), and each unit is in native-endian byte order.
             #[cfg(feature = #feature)]
-            #[derive(Clone)]
+            #[derive(#derives)]
+            #[cfg_attr(
+                feature = "support_zerocopy",
+                derive(zerocopy::FromBytes, zerocopy::AsBytes)
+            )]
+            #[repr(transparent)]
             pub struct #name(pub #inner_type);
         );
         self.attach_uint(part);
@@ -174,11 +191,20 @@ impl UintConstructor {
             if errors.is_empty() {
                 quote!()
             } else {
-                let errors = TokenStream::from_iter(errors);
+                let (variants, displays): (Vec<_>, Vec<_>) = errors.into_iter().unzip();
                 quote!(
-                    #[derive(Debug, Error)]
+                    #[derive(Debug)]
+                    #[cfg_attr(feature = "std", derive(Error))]
                     pub enum #error_name {
-                        #errors
+                        #(#variants)*
+                    }
+                    impl ::core::fmt::Display for #error_name {
+                        #[inline]
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                #(#displays)*
+                            }
+                        }
                     }
                 )
             }
@@ -249,11 +275,13 @@ impl UintConstructor {
             // define common part for all fixed uints
             TokenStream::from_iter(self.common.take())
         } else {
-            // define convert methods (From, Into) between two fixed uints
+            // define convert methods (From, Into) and comparisons between two fixed uints
             ucs.iter().fold(quote!(), |all, ref uc| {
                 let convert_into = self.convert_into(uc);
                 let convert_from = uc.convert_into(self);
-                quote!(#all #convert_into #convert_from)
+                let cmp_with = self.impl_traits_std_cmp_between(uc);
+                let cmp_from = uc.impl_traits_std_cmp_between(self);
+                quote!(#all #convert_into #convert_from #cmp_with #cmp_from)
             })
         };
         (one_uint, public)
@@ -292,10 +320,29 @@ impl UintConstructor {
         self.common.set(o);
     }
 
-    pub fn error(&self, part: TokenStream) {
+    /// Add a variant which wraps an inner error into the main error, and the conversion for it.
+    pub fn error(&self, variant: &str, inner: &str, explain: &str) {
+        let error_name = &self.ts.error_name;
+        let variant = utils::ident_to_ts(variant);
+        let inner = utils::ident_to_ts(inner);
+        let part = quote!(
+            #variant(#[cfg_attr(feature = "std", source)] #inner),
+        );
+        let display = quote!(
+            #error_name::#variant(err) => write!(f, #explain, err),
+        );
         let mut o = self.errors.take();
-        o.push(part);
+        o.push((part, display));
         self.errors.set(o);
+        let part = quote!(
+            impl ::core::convert::From<#inner> for #error_name {
+                #[inline]
+                fn from(err: #inner) -> Self {
+                    #error_name::#variant(err)
+                }
+            }
+        );
+        self.attach_common(part);
     }
 
     pub fn util(&self, part: TokenStream) {
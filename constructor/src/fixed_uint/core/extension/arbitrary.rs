@@ -0,0 +1,39 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`arbitrary`] for generating structured data from fuzzer inputs.
+//!
+//! [`arbitrary`]: https://crates.io/crates/arbitrary
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_arbitrary(&self) {
+        self.with_arbitrary_impl();
+    }
+
+    fn with_arbitrary_impl(&self) {
+        let name = &self.ts.name;
+        let inner_type = &self.ts.inner_type;
+        let part = quote!(
+            #[cfg(feature = "support_arbitrary")]
+            impl<'a> arbitrary::Arbitrary<'a> for #name {
+                #[inline]
+                fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    <#inner_type as arbitrary::Arbitrary<'a>>::arbitrary(u).map(Self::new)
+                }
+                #[inline]
+                fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                    <#inner_type as arbitrary::Arbitrary<'a>>::size_hint(depth)
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -10,6 +10,11 @@
 //!
 //! The dependencies should be in the `Cargo.toml`.
 
+mod arbitrary;
+mod bytemuck;
 mod heapsize;
+mod num_traits;
+mod proptest;
+mod quickcheck;
 mod rand;
 mod serde;
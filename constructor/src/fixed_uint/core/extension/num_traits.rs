@@ -0,0 +1,372 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`num-traits`] and [`num-integer`] for generic numeric programming.
+//!
+//! [`num-traits`]: https://crates.io/crates/num-traits
+//! [`num-integer`]: https://crates.io/crates/num-integer
+
+use crate::fixed_uint::UintConstructor;
+use crate::utils;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_num_traits(&self) {
+        self.with_num_traits_identities();
+        self.with_num_traits_num();
+        self.with_num_traits_bounds();
+        self.with_num_traits_ops_checked();
+        self.with_num_traits_ops_saturating();
+        self.with_num_traits_ops_wrapping();
+        self.with_num_traits_pow();
+        self.with_num_traits_cast();
+        self.with_num_integer();
+    }
+
+    fn with_num_traits_identities(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::Zero for #name {
+                #[inline]
+                fn zero() -> Self {
+                    #name::zero()
+                }
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    #name::is_zero(self)
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::One for #name {
+                #[inline]
+                fn one() -> Self {
+                    #name::one()
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_num_traits_num(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::Num for #name {
+                type FromStrRadixErr = #error_name;
+                #[inline]
+                fn from_str_radix(input: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                    #name::from_str_radix(input, radix)
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::Unsigned for #name {}
+        );
+        self.implt(part);
+    }
+
+    fn with_num_traits_bounds(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::Bounded for #name {
+                #[inline]
+                fn min_value() -> Self {
+                    #name::min_value()
+                }
+                #[inline]
+                fn max_value() -> Self {
+                    #name::max_value()
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_num_traits_ops_checked(&self) {
+        let name = &self.ts.name;
+        for (trait_name, func_name) in &[
+            ("CheckedAdd", "checked_add"),
+            ("CheckedSub", "checked_sub"),
+            ("CheckedMul", "checked_mul"),
+            ("CheckedDiv", "checked_div"),
+            ("CheckedRem", "checked_rem"),
+        ] {
+            let trait_name = utils::ident_to_ts(trait_name);
+            let func_name = utils::ident_to_ts(func_name);
+            let part = quote!(
+                #[cfg(feature = "support_num_traits")]
+                impl num_traits::#trait_name for #name {
+                    #[inline]
+                    fn #func_name(&self, v: &Self) -> Option<Self> {
+                        #name::#func_name(self, v)
+                    }
+                }
+            );
+            self.implt(part);
+        }
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::CheckedNeg for #name {
+                #[inline]
+                fn checked_neg(&self) -> Option<Self> {
+                    #name::checked_neg(self)
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::CheckedShl for #name {
+                #[inline]
+                fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                    #name::checked_shl(self, u128::from(rhs))
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::CheckedShr for #name {
+                #[inline]
+                fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                    #name::checked_shr(self, u128::from(rhs))
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_num_traits_ops_saturating(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::Saturating for #name {
+                #[inline]
+                fn saturating_add(self, v: Self) -> Self {
+                    #name::saturating_add(&self, &v)
+                }
+                #[inline]
+                fn saturating_sub(self, v: Self) -> Self {
+                    #name::saturating_sub(&self, &v)
+                }
+            }
+        );
+        self.implt(part);
+        for (trait_name, func_name) in &[
+            ("SaturatingAdd", "saturating_add"),
+            ("SaturatingSub", "saturating_sub"),
+            ("SaturatingMul", "saturating_mul"),
+        ] {
+            let trait_name = utils::ident_to_ts(trait_name);
+            let func_name = utils::ident_to_ts(func_name);
+            let part = quote!(
+                #[cfg(feature = "support_num_traits")]
+                impl num_traits::#trait_name for #name {
+                    #[inline]
+                    fn #func_name(&self, v: &Self) -> Self {
+                        #name::#func_name(self, v)
+                    }
+                }
+            );
+            self.implt(part);
+        }
+    }
+
+    fn with_num_traits_ops_wrapping(&self) {
+        let name = &self.ts.name;
+        for (trait_name, func_name, real_func) in &[
+            ("WrappingAdd", "wrapping_add", "overflowing_add"),
+            ("WrappingSub", "wrapping_sub", "overflowing_sub"),
+            ("WrappingMul", "wrapping_mul", "overflowing_mul"),
+        ] {
+            let trait_name = utils::ident_to_ts(trait_name);
+            let func_name = utils::ident_to_ts(func_name);
+            let real_func = utils::ident_to_ts(real_func);
+            let part = quote!(
+                #[cfg(feature = "support_num_traits")]
+                impl num_traits::#trait_name for #name {
+                    #[inline]
+                    fn #func_name(&self, v: &Self) -> Self {
+                        #name::#real_func(self, v).0
+                    }
+                }
+            );
+            self.implt(part);
+        }
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::WrappingNeg for #name {
+                #[inline]
+                fn wrapping_neg(&self) -> Self {
+                    #name::overflowing_neg(self).0
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::WrappingShl for #name {
+                #[inline]
+                fn wrapping_shl(&self, rhs: u32) -> Self {
+                    #name::overflowing_shl(self, u128::from(rhs)).0
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::WrappingShr for #name {
+                #[inline]
+                fn wrapping_shr(&self, rhs: u32) -> Self {
+                    #name::overflowing_shr(self, u128::from(rhs)).0
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_num_traits_pow(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::Pow<u32> for #name {
+                type Output = #name;
+                #[inline]
+                fn pow(self, rhs: u32) -> Self::Output {
+                    #name::pow(&self, rhs)
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl<'a> num_traits::Pow<u32> for &'a #name {
+                type Output = #name;
+                #[inline]
+                fn pow(self, rhs: u32) -> Self::Output {
+                    #name::pow(self, rhs)
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_num_traits_cast(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::ToPrimitive for #name {
+                #[inline]
+                fn to_i64(&self) -> Option<i64> {
+                    match self.highest_one() {
+                        Some(idx) if idx >= 63 => None,
+                        _ => Some(self._into_u64() as i64),
+                    }
+                }
+                #[inline]
+                fn to_u64(&self) -> Option<u64> {
+                    match self.highest_one() {
+                        Some(idx) if idx >= 64 => None,
+                        _ => Some(self._into_u64()),
+                    }
+                }
+                #[inline]
+                fn to_i128(&self) -> Option<i128> {
+                    match self.highest_one() {
+                        Some(idx) if idx >= 127 => None,
+                        _ => Some(self._into_u128() as i128),
+                    }
+                }
+                #[inline]
+                fn to_u128(&self) -> Option<u128> {
+                    match self.highest_one() {
+                        Some(idx) if idx >= 128 => None,
+                        _ => Some(self._into_u128()),
+                    }
+                }
+                #[inline]
+                fn to_f32(&self) -> Option<f32> {
+                    Some(#name::to_f32(self))
+                }
+                #[inline]
+                fn to_f64(&self) -> Option<f64> {
+                    Some(#name::to_f64(self))
+                }
+            }
+            #[cfg(feature = "support_num_traits")]
+            impl num_traits::FromPrimitive for #name {
+                #[inline]
+                fn from_i64(n: i64) -> Option<Self> {
+                    if n < 0 {
+                        None
+                    } else {
+                        Some(#name::_from_u64(n as u64))
+                    }
+                }
+                #[inline]
+                fn from_u64(n: u64) -> Option<Self> {
+                    Some(#name::_from_u64(n))
+                }
+                #[inline]
+                fn from_i128(n: i128) -> Option<Self> {
+                    if n < 0 {
+                        None
+                    } else {
+                        Some(#name::_from_u128(n as u128))
+                    }
+                }
+                #[inline]
+                fn from_u128(n: u128) -> Option<Self> {
+                    Some(#name::_from_u128(n))
+                }
+                #[inline]
+                fn from_f64(n: f64) -> Option<Self> {
+                    #name::try_from_f64(n).ok()
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_num_integer(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(feature = "support_num_traits")]
+            impl num_integer::Integer for #name {
+                #[inline]
+                fn div_floor(&self, other: &Self) -> Self {
+                    self / other
+                }
+                #[inline]
+                fn mod_floor(&self, other: &Self) -> Self {
+                    self % other
+                }
+                #[inline]
+                fn gcd(&self, other: &Self) -> Self {
+                    #name::gcd(self, other)
+                }
+                #[inline]
+                fn lcm(&self, other: &Self) -> Self {
+                    if self.is_zero() && other.is_zero() {
+                        Self::zero()
+                    } else {
+                        self / &#name::gcd(self, other) * other
+                    }
+                }
+                #[inline]
+                fn is_multiple_of(&self, other: &Self) -> bool {
+                    if other.is_zero() {
+                        self.is_zero()
+                    } else {
+                        (self % other).is_zero()
+                    }
+                }
+                #[inline]
+                fn is_even(&self) -> bool {
+                    self.inner()[0] & 1 == 0
+                }
+                #[inline]
+                fn is_odd(&self) -> bool {
+                    self.inner()[0] & 1 == 1
+                }
+                #[inline]
+                fn div_rem(&self, other: &Self) -> (Self, Self) {
+                    self.complete_div(other)
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
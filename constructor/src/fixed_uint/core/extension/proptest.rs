@@ -0,0 +1,162 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`proptest`] for property testing.
+//!
+//! Values shrink limb by limb toward zero.
+//! Pairs are generated for an operator, so the operator will not overflow or panic.
+//! For multiplication, the bit lengths of the pair are chosen first, and their sum is not greater
+//! than the bit length of the fixed uint.
+//!
+//! [`proptest`]: https://crates.io/crates/proptest
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_proptest(&self) {
+        self.with_proptest_common();
+        self.with_proptest_impl();
+        self.with_proptest_defun_pub();
+    }
+
+    fn with_proptest_common(&self) {
+        let part = quote!(
+            /// Parameters for the strategies of pairs of fixed uints.
+            #[cfg(feature = "support_proptest")]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub enum PairParameters {
+                /// Any pair.
+                Random,
+                /// The sum of the pair does not overflow.
+                CanAdd,
+                /// The left is not less than the right.
+                CanSub,
+                /// The product of the pair does not overflow.
+                CanMul,
+                /// The right is in `[1, left]`, or is one if the left is zero.
+                CanDiv,
+                /// The right is in `[1, left]`, or is one if the left is zero.
+                CanRem,
+            }
+            #[cfg(feature = "support_proptest")]
+            impl ::core::default::Default for PairParameters {
+                #[inline]
+                fn default() -> Self {
+                    PairParameters::Random
+                }
+            }
+        );
+        self.attach_common(part);
+    }
+
+    fn with_proptest_impl(&self) {
+        let name = &self.ts.name;
+        let unit_suffix = &self.ts.unit_suffix;
+        let unit_amount = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(feature = "support_proptest")]
+            impl proptest::arbitrary::Arbitrary for #name {
+                type Parameters = ();
+                type Strategy = proptest::strategy::BoxedStrategy<Self>;
+                #[inline]
+                fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+                    use proptest::strategy::Strategy;
+                    proptest::collection::vec(proptest::num::#unit_suffix::ANY, #unit_amount)
+                        .prop_map(|units| {
+                            let mut inner = [0; #unit_amount];
+                            inner.copy_from_slice(&units[..]);
+                            Self::new(inner)
+                        })
+                        .boxed()
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_proptest_defun_pub(&self) {
+        let bits_size = &self.ts.bits_size;
+        let part = quote!(
+            /// Create a strategy for pairs of fixed uints, which satisfy the parameters.
+            #[cfg(feature = "support_proptest")]
+            #[inline]
+            pub fn pair_strategy(
+                params: PairParameters,
+            ) -> impl proptest::strategy::Strategy<Value = (Self, Self)> {
+                use proptest::strategy::{Just, Strategy};
+                if params == PairParameters::CanMul {
+                    // Choose the bit lengths first, so the product always fits.
+                    return (0u128..=#bits_size)
+                        .prop_flat_map(|lhs_bits| (Just(lhs_bits), 0..=#bits_size - lhs_bits))
+                        .prop_flat_map(|(lhs_bits, rhs_bits)| {
+                            let max = Self::max_value();
+                            (
+                                Self::proptest_between(
+                                    Self::zero(),
+                                    max._ushr(#bits_size - lhs_bits),
+                                ),
+                                Self::proptest_between(
+                                    Self::zero(),
+                                    max._ushr(#bits_size - rhs_bits),
+                                ),
+                            )
+                        })
+                        .boxed();
+                }
+                proptest::arbitrary::any::<Self>()
+                    .prop_flat_map(move |lhs| {
+                        let max = Self::max_value();
+                        let rhs = match params {
+                            PairParameters::Random => Self::proptest_between(Self::zero(), max),
+                            PairParameters::CanAdd => {
+                                Self::proptest_between(Self::zero(), max - &lhs)
+                            }
+                            PairParameters::CanSub => {
+                                Self::proptest_between(Self::zero(), lhs.clone())
+                            }
+                            PairParameters::CanMul => unreachable!(),
+                            PairParameters::CanDiv | PairParameters::CanRem => {
+                                if lhs.is_zero() {
+                                    Self::proptest_between(Self::one(), Self::one())
+                                } else {
+                                    Self::proptest_between(Self::one(), lhs.clone())
+                                }
+                            }
+                        };
+                        (Just(lhs), rhs)
+                    })
+                    .boxed()
+            }
+            /// Create a strategy for fixed uints in the range from `min` to `max` (inclusive).
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `min` is greater than `max`.
+            #[cfg(feature = "support_proptest")]
+            #[inline]
+            pub fn proptest_between(
+                min: Self,
+                max: Self,
+            ) -> proptest::strategy::BoxedStrategy<Self> {
+                use proptest::strategy::Strategy;
+                let diff = max - &min;
+                proptest::arbitrary::any::<Self>()
+                    .prop_map(move |val| {
+                        if diff.is_max() {
+                            val
+                        } else {
+                            &min + val % (&diff + Self::one())
+                        }
+                    })
+                    .boxed()
+            }
+        );
+        self.defun(part);
+    }
+}
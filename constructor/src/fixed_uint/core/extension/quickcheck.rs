@@ -0,0 +1,88 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`quickcheck`] for property testing.
+//!
+//! Generated values are mixed with edge cases, such as zero, one, max, powers of two and
+//! single-limb values.
+//! Each shrunk value has fewer set bits or lives in lower limbs, so counterexamples are minimal.
+//!
+//! [`quickcheck`]: https://crates.io/crates/quickcheck
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_quickcheck(&self) {
+        self.with_quickcheck_impl();
+    }
+
+    fn with_quickcheck_impl(&self) {
+        let name = &self.ts.name;
+        let bits_size = &self.ts.bits_size;
+        let unit_bits_size = &self.ts.unit_bits_size;
+        let unit_suffix = &self.ts.unit_suffix;
+        let part = quote!(
+            #[cfg(feature = "support_quickcheck")]
+            impl quickcheck::Arbitrary for #name {
+                fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                    let mut ret = Self::zero();
+                    match <u8 as quickcheck::Arbitrary>::arbitrary(g) % 8 {
+                        0 => {}
+                        1 => ret = Self::one(),
+                        2 => ret = Self::max_value(),
+                        3 => {
+                            let idx = <usize as quickcheck::Arbitrary>::arbitrary(g) % #bits_size;
+                            ret.set_bit(idx, true);
+                        }
+                        4 => {
+                            let idx = <usize as quickcheck::Arbitrary>::arbitrary(g) % #bits_size;
+                            ret.set_bit(idx, true);
+                            ret -= Self::one();
+                        }
+                        5 => {
+                            ret.mut_inner()[0] =
+                                <#unit_suffix as quickcheck::Arbitrary>::arbitrary(g)
+                        }
+                        _ => {
+                            for unit in ret.mut_inner().iter_mut() {
+                                *unit = <#unit_suffix as quickcheck::Arbitrary>::arbitrary(g);
+                            }
+                        }
+                    }
+                    ret
+                }
+                fn shrink(&self) -> ::alloc::boxed::Box<dyn Iterator<Item = Self>> {
+                    let mut ret = ::alloc::vec::Vec::new();
+                    if let (Some(highest), Some(lowest)) = (self.highest_one(), self.lowest_one()) {
+                        ret.push(Self::zero());
+                        // move to lower limbs
+                        if highest >= #unit_bits_size {
+                            ret.push(self >> (#unit_bits_size as usize));
+                            let mut lower = self.clone();
+                            lower.mut_inner()[highest / #unit_bits_size] = 0;
+                            ret.push(lower);
+                        }
+                        // clear a set bit
+                        if highest != lowest {
+                            let mut cleared = self.clone();
+                            cleared.set_bit(highest, false);
+                            ret.push(cleared);
+                            let mut cleared = self.clone();
+                            cleared.set_bit(lowest, false);
+                            ret.push(cleared);
+                        }
+                        ret.dedup();
+                    }
+                    ::alloc::boxed::Box::new(ret.into_iter())
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -11,7 +11,6 @@
 //! [`rand`]: https://crates.io/crates/rand
 
 use crate::fixed_uint::UintConstructor;
-use crate::utils;
 use quote::quote;
 
 impl UintConstructor {
@@ -21,26 +20,16 @@ impl UintConstructor {
 
     fn with_rand_defun_pub(&self) {
         let name = &self.ts.name;
-        let bytes_size = &self.ts.bytes_size;
-        let inner_type = &self.ts.inner_type;
-        let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let part = quote!(
             #[cfg(feature = "support_rand")]
             impl rand::AsByteSliceMut for #name {
                 #[inline]
                 fn as_byte_slice_mut(&mut self) -> &mut [u8] {
-                    let inner = self.mut_inner();
-                    unsafe {
-                        &mut *(inner as *mut #inner_type as *mut [u8; #bytes_size])
-                    }
+                    rand::AsByteSliceMut::as_byte_slice_mut(&mut self.mut_inner()[..])
                 }
                 #[inline]
                 fn to_le(&mut self) {
-                    let inner = self.mut_inner();
-                    #({
-                        let idx = #loop_unit_amount;
-                        inner[idx] = inner[idx].to_le();
-                    })*
+                    rand::AsByteSliceMut::to_le(&mut self.mut_inner()[..])
                 }
             }
         );
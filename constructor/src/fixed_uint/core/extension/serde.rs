@@ -11,16 +11,23 @@
 //! [`serde`]: https://crates.io/crates/serde
 
 use crate::fixed_uint::UintConstructor;
+use crate::utils;
 use quote::quote;
 
 impl UintConstructor {
     pub fn with_serde(&self) {
         self.with_serde_defun_pub();
+        self.with_serde_helpers();
+        self.with_serde_helpers_impl();
     }
 
     fn with_serde_defun_pub(&self) {
         let name = &self.ts.name;
+        let bits_size = &self.ts.bits_size;
         let bytes_size = &self.ts.bytes_size;
+        let unit_bits_size = &self.ts.unit_bits_size;
+        let unit_bytes_size = &self.ts.unit_bytes_size;
+        let unit_suffix = &self.ts.unit_suffix;
         let part = quote!(
             #[cfg(feature = "support_serde")]
             impl serde::Serialize for #name {
@@ -28,49 +35,32 @@ impl UintConstructor {
                 where
                     S: serde::Serializer,
                 {
-                    fn hex_to(src: &[u8], dst: &mut [u8]) -> usize {
-                        fn hex(byte: u8) -> u8 {
-                            static TABLE: &[u8] = b"0123456789abcdef";
-                            TABLE[byte as usize]
-                        }
-
-                        let mut len = src.len() * 2;
-                        let mut idx = 0;
-                        let first_nibble = src[0] >> 4;
-                        if first_nibble != 0 {
-                            dst[idx] = hex(src[0] >> 4);
-                            idx += 1;
-                        } else {
-                            len -= 1;
-                        }
-                        dst[idx] = hex(src[0] & 0xf);
-                        idx += 1;
-
-                        for (byte, slots) in src.iter().skip(1).zip(dst[idx..].chunks_mut(2)) {
-                            slots[0] = hex(*byte >> 4);
-                            slots[1] = hex(*byte & 0xf);
-                        }
-                        len
+                    let leading_zeros = self.leading_zeros() as usize;
+                    if leading_zeros == #bits_size {
+                        return serializer.serialize_str("0x0");
                     }
-
+                    // big-endian bytes, start from the highest non-zero unit
                     let mut bytes = [0u8; #bytes_size];
-                    let mut dst = [0u8; #bytes_size * 2 + 2];
-                    dst[0] = b'0';
-                    dst[1] = b'x';
-                    self.into_big_endian(&mut bytes)
-                        .map_err(|e| serde::ser::Error::custom(&format!("{}", e)))?;
-
-                    let non_zero = bytes.iter().position(|&b| b != 0);
-
-                    if let Some(non_zero_idx) = non_zero {
-                        let bytes = &bytes[non_zero_idx..];
-                        let len = hex_to(bytes, &mut dst[2..]);
-                        serializer.serialize_str(unsafe {
-                            ::std::str::from_utf8_unchecked(&dst[..(len + 2)])
-                        })
-                    } else {
-                        serializer.serialize_str("0x0")
+                    let skip_units = leading_zeros / #unit_bits_size;
+                    for (chunk, unit) in bytes
+                        .chunks_mut(#unit_bytes_size)
+                        .zip(self.inner().iter().rev())
+                        .skip(skip_units)
+                    {
+                        chunk.copy_from_slice(&unit.to_be_bytes());
                     }
+                    let src = &bytes[(leading_zeros / 8)..];
+                    let mut dst = [0u8; #bytes_size * 2 + 2];
+                    faster_hex::hex_encode(src, &mut dst[2..])
+                        .map_err(serde::ser::Error::custom)?;
+                    // skip the leading zero nibble
+                    let start = if leading_zeros % 8 >= 4 { 1 } else { 0 };
+                    dst[start] = b'0';
+                    dst[start + 1] = b'x';
+                    let len = src.len() * 2 + 2;
+                    serializer.serialize_str(unsafe {
+                        ::core::str::from_utf8_unchecked(&dst[start..len])
+                    })
                 }
             }
 
@@ -87,8 +77,8 @@ impl UintConstructor {
 
                         fn expecting(
                             &self,
-                            formatter: &mut ::std::fmt::Formatter,
-                        ) -> ::std::fmt::Result {
+                            formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
                             write!(
                                 formatter,
                                 "a 0x-prefixed, no leading zeroes allowed hex string with at most {} digits",
@@ -112,22 +102,357 @@ impl UintConstructor {
                                 return Err(E::invalid_length(v.len() - 2, &self));
                             }
 
-                            #name::from_hex_str(&v[2..]).map_err(|e| {
+                            let digits = &v.as_bytes()[2..];
+                            let mut src = [b'0'; #bytes_size * 2];
+                            src[(#bytes_size * 2 - digits.len())..].copy_from_slice(digits);
+                            let mut bytes = [0u8; #bytes_size];
+                            faster_hex::hex_decode(&src, &mut bytes).map_err(|e| {
                                 E::custom(format_args!(
                                     "invalid hex bytes: {:?}, expected {}",
                                     e, &self as &serde::de::Expected
                                 ))
-                            })
+                            })?;
+                            let mut ret = #name::zero();
+                            for (unit, chunk) in ret
+                                .mut_inner()
+                                .iter_mut()
+                                .rev()
+                                .zip(bytes.chunks(#unit_bytes_size))
+                            {
+                                let mut buf = [0u8; #unit_bytes_size];
+                                buf.copy_from_slice(chunk);
+                                *unit = #unit_suffix::from_be_bytes(buf);
+                            }
+                            Ok(ret)
                         }
+                    }
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+        );
+        self.implt(part);
+    }
 
-                        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-                        where
-                            E: serde::de::Error,
-                        {
-                            self.visit_str(&v)
+    fn with_serde_helpers(&self) {
+        let error_name = &self.ts.error_name;
+        let modules = [
+            (
+                "dec",
+                "Dec",
+                "Serialize as a decimal string, for example, `\"1234\"`.",
+            ),
+            (
+                "hex_padded",
+                "HexPadded",
+                "Serialize as a `0x`-prefixed hexadecimal string with the full width, \
+                 for example, `\"0x00000000000004d2\"` for a 64 bits uint.",
+            ),
+            (
+                "be_bytes",
+                "BeBytes",
+                "Serialize as the big-endian bytes with the full width, \
+                 even if the format is human readable.",
+            ),
+            (
+                "number",
+                "Number",
+                "Serialize as a number if the value fits `u64`, otherwise, as a decimal string.",
+            ),
+            (
+                "lenient",
+                "Lenient",
+                "Serialize as the default format, and deserialize from any format: \
+                 a number, a decimal string, a `0x`-prefixed hexadecimal string with or without \
+                 the leading zeros, or the big-endian bytes.",
+            ),
+        ];
+        let helpers = modules.iter().map(|(mod_name, format, doc)| {
+            let mod_name = utils::ident_to_ts(mod_name);
+            let format = utils::ident_to_ts(format);
+            let doc = format!(
+                "{}\n\nThe big-endian bytes are used if the format is not human readable.",
+                doc
+            );
+            quote!(
+                #[doc = #doc]
+                pub mod #mod_name {
+                    use super::{Format, UintSerde};
+
+                    /// Serialize the uint.
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: UintSerde,
+                        S: serde::Serializer,
+                    {
+                        super::serialize(value, serializer, Format::#format)
+                    }
+
+                    /// Deserialize the uint.
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: UintSerde,
+                        D: serde::Deserializer<'de>,
+                    {
+                        super::deserialize(deserializer, Format::#format)
+                    }
+                }
+            )
+        });
+        let part_core = quote!(#(#helpers)*);
+        let part = quote!(
+            /// Helpers for `#[serde(with = "..")]` to use different representations for the uints.
+            #[cfg(feature = "support_serde")]
+            pub mod serde_helpers {
+                use core::{fmt, marker::PhantomData};
+
+                /// The conversions which are used by the serde helpers.
+                pub trait UintSerde: Sized + serde::Serialize {
+                    /// The size of the uint in bytes.
+                    const BYTES_SIZE: usize;
+                    /// Call the function with the decimal string.
+                    fn with_dec_str<R, F: FnOnce(&str) -> R>(&self, func: F) -> R;
+                    /// Call the function with the `0x`-prefixed hexadecimal string with the full
+                    /// width.
+                    fn with_hex_padded_str<R, F: FnOnce(&str) -> R>(&self, func: F) -> R;
+                    /// Call the function with the big-endian bytes.
+                    fn with_be_bytes<R, F: FnOnce(&[u8]) -> R>(&self, func: F) -> R;
+                    /// Convert into `u64` if the value fits.
+                    fn to_u64_checked(&self) -> Option<u64>;
+                    /// Convert from `u64`.
+                    fn from_u64_value(value: u64) -> Self;
+                    /// Convert from a decimal string without leading zeros.
+                    fn from_dec_value(value: &str) -> Result<Self, super::#error_name>;
+                    /// Convert from a hexadecimal string without leading zeros.
+                    fn from_hex_value(value: &str) -> Result<Self, super::#error_name>;
+                    /// Convert from big-endian bytes, which could be shorter than the full width.
+                    fn from_be_bytes_value(value: &[u8]) -> Result<Self, super::#error_name>;
+                }
+
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                enum Format {
+                    Dec,
+                    HexPadded,
+                    BeBytes,
+                    Number,
+                    Lenient,
+                }
+
+                fn trim_leading_zeros(input: &str) -> &str {
+                    let trimmed = input.trim_start_matches('0');
+                    if trimmed.is_empty() && !input.is_empty() {
+                        "0"
+                    } else {
+                        trimmed
+                    }
+                }
+
+                fn serialize<T, S>(
+                    value: &T,
+                    serializer: S,
+                    format: Format,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    T: UintSerde,
+                    S: serde::Serializer,
+                {
+                    if format == Format::BeBytes || !serializer.is_human_readable() {
+                        return value.with_be_bytes(|bytes| serializer.serialize_bytes(bytes));
+                    }
+                    match format {
+                        Format::Dec => value.with_dec_str(|s| serializer.serialize_str(s)),
+                        Format::HexPadded => {
+                            value.with_hex_padded_str(|s| serializer.serialize_str(s))
                         }
+                        Format::Number => {
+                            if let Some(v) = value.to_u64_checked() {
+                                serializer.serialize_u64(v)
+                            } else {
+                                value.with_dec_str(|s| serializer.serialize_str(s))
+                            }
+                        }
+                        _ => value.serialize(serializer),
                     }
-                    deserializer.deserialize_str(Visitor)
+                }
+
+                fn deserialize<'de, T, D>(deserializer: D, format: Format) -> Result<T, D::Error>
+                where
+                    T: UintSerde,
+                    D: serde::Deserializer<'de>,
+                {
+                    let visitor = Visitor(format, PhantomData);
+                    if format == Format::BeBytes || !deserializer.is_human_readable() {
+                        return deserializer.deserialize_bytes(visitor);
+                    }
+                    match format {
+                        Format::Dec | Format::HexPadded => deserializer.deserialize_str(visitor),
+                        _ => deserializer.deserialize_any(visitor),
+                    }
+                }
+
+                struct Visitor<T>(Format, PhantomData<T>);
+
+                impl<T: UintSerde> Visitor<T> {
+                    fn parse_str<E: serde::de::Error>(&self, v: &str) -> Result<T, E> {
+                        let result = match self.0 {
+                            Format::Dec | Format::Number => {
+                                T::from_dec_value(trim_leading_zeros(v))
+                            }
+                            Format::HexPadded => {
+                                if v.len() != T::BYTES_SIZE * 2 + 2 || !v.starts_with("0x") {
+                                    return Err(E::invalid_value(
+                                        serde::de::Unexpected::Str(v),
+                                        self,
+                                    ));
+                                }
+                                T::from_hex_value(trim_leading_zeros(&v[2..]))
+                            }
+                            Format::Lenient => {
+                                if v.starts_with("0x") || v.starts_with("0X") {
+                                    T::from_hex_value(trim_leading_zeros(&v[2..]))
+                                } else {
+                                    T::from_dec_value(trim_leading_zeros(v))
+                                }
+                            }
+                            Format::BeBytes => {
+                                return Err(E::invalid_type(serde::de::Unexpected::Str(v), self));
+                            }
+                        };
+                        result.map_err(|err| {
+                            let expected = self as &dyn serde::de::Expected;
+                            E::custom(format_args!("{}, expected {}", err, expected))
+                        })
+                    }
+                }
+
+                impl<'de, T: UintSerde> serde::de::Visitor<'de> for Visitor<T> {
+                    type Value = T;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        match self.0 {
+                            Format::Dec => write!(f, "a decimal string"),
+                            Format::HexPadded => write!(
+                                f,
+                                "a 0x-prefixed hex string with {} digits",
+                                T::BYTES_SIZE * 2
+                            ),
+                            Format::BeBytes => write!(f, "{} big-endian bytes", T::BYTES_SIZE),
+                            Format::Number => write!(f, "an unsigned integer or a decimal string"),
+                            Format::Lenient => write!(
+                                f,
+                                "an unsigned integer, a decimal string, a 0x-prefixed hex string \
+                                 or at most {} big-endian bytes",
+                                T::BYTES_SIZE
+                            ),
+                        }
+                    }
+
+                    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<T, E> {
+                        match self.0 {
+                            Format::Number | Format::Lenient => Ok(T::from_u64_value(v)),
+                            _ => Err(E::invalid_type(serde::de::Unexpected::Unsigned(v), &self)),
+                        }
+                    }
+
+                    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<T, E> {
+                        if v < 0 {
+                            Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+                        } else {
+                            self.visit_u64(v as u64)
+                        }
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> {
+                        self.parse_str(v)
+                    }
+
+                    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<T, E> {
+                        if self.0 != Format::Lenient && v.len() != T::BYTES_SIZE {
+                            return Err(E::invalid_length(v.len(), &self));
+                        }
+                        T::from_be_bytes_value(v).map_err(|_| E::invalid_length(v.len(), &self))
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+                    where
+                        A: serde::de::SeqAccess<'de>,
+                    {
+                        let mut bytes = ::alloc::vec::Vec::with_capacity(T::BYTES_SIZE);
+                        while let Some(byte) = seq.next_element::<u8>()? {
+                            if bytes.len() == T::BYTES_SIZE {
+                                let len = bytes.len() + 1;
+                                return Err(serde::de::Error::invalid_length(len, &self));
+                            }
+                            bytes.push(byte);
+                        }
+                        self.visit_bytes(&bytes)
+                    }
+                }
+
+                #part_core
+            }
+        );
+        self.attach_common(part);
+    }
+
+    fn with_serde_helpers_impl(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let bits_size = &self.ts.bits_size;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(feature = "support_serde")]
+            impl serde_helpers::UintSerde for #name {
+                const BYTES_SIZE: usize = #bytes_size;
+                #[inline]
+                fn with_dec_str<R, F: FnOnce(&str) -> R>(&self, func: F) -> R {
+                    let mut buf = [0u8; #bits_size];
+                    func(self.write_dec_to(&mut buf))
+                }
+                #[inline]
+                fn with_hex_padded_str<R, F: FnOnce(&str) -> R>(&self, func: F) -> R {
+                    const CHARS: &[u8] = b"0123456789abcdef";
+                    let mut bytes = [0u8; #bytes_size];
+                    self.into_big_endian(&mut bytes)
+                        .unwrap_or_else(|_| unreachable!());
+                    let mut buf = [0u8; #bytes_size * 2 + 2];
+                    buf[0] = b'0';
+                    buf[1] = b'x';
+                    for (byte, slots) in bytes.iter().zip(buf[2..].chunks_mut(2)) {
+                        slots[0] = CHARS[(byte >> 4) as usize];
+                        slots[1] = CHARS[(byte & 0xf) as usize];
+                    }
+                    // all characters are ASCII characters
+                    func(::core::str::from_utf8(&buf[..]).unwrap())
+                }
+                #[inline]
+                fn with_be_bytes<R, F: FnOnce(&[u8]) -> R>(&self, func: F) -> R {
+                    let mut bytes = [0u8; #bytes_size];
+                    self.into_big_endian(&mut bytes)
+                        .unwrap_or_else(|_| unreachable!());
+                    func(&bytes[..])
+                }
+                #[inline]
+                fn to_u64_checked(&self) -> Option<u64> {
+                    match self.highest_one() {
+                        Some(idx) if idx >= 64 => None,
+                        _ => Some(self._into_u64()),
+                    }
+                }
+                #[inline]
+                fn from_u64_value(value: u64) -> Self {
+                    Self::_from_u64(value)
+                }
+                #[inline]
+                fn from_dec_value(value: &str) -> Result<Self, #error_name> {
+                    Self::from_dec_str(value)
+                }
+                #[inline]
+                fn from_hex_value(value: &str) -> Result<Self, #error_name> {
+                    Self::from_hex_str(value)
+                }
+                #[inline]
+                fn from_be_bytes_value(value: &[u8]) -> Result<Self, #error_name> {
+                    Self::from_big_endian(value)
                 }
             }
         );
@@ -19,6 +19,8 @@ impl UintConstructor {
         self.defun_priv_kernel();
         self.defun_pub_kernel();
         self.deftrait_uint_convert();
+        self.attach_error_for_conv_narrowing();
+        self.defun_pub_truncate_into();
     }
 
     pub fn convert_into(&self, uc: &Self) -> TokenStream {
@@ -82,7 +84,50 @@ impl UintConstructor {
                 )
             }
         };
-        quote!(
+        let std_convert = if self.info.bits_size <= uc.info.bits_size {
+            quote!(
+                #[cfg(all(feature = #this_feature, feature = #that_feature))]
+                impl<'a> ::core::convert::From<&'a #this_name> for #that_name {
+                    #[inline]
+                    fn from(value: &#this_name) -> Self {
+                        prelude::UintConvert::convert_into(value).0
+                    }
+                }
+                #[cfg(all(feature = #this_feature, feature = #that_feature))]
+                impl ::core::convert::From<#this_name> for #that_name {
+                    #[inline]
+                    fn from(value: #this_name) -> Self {
+                        prelude::UintConvert::convert_into(&value).0
+                    }
+                }
+            )
+        } else {
+            let that_bits_size = &uc.ts.bits_size;
+            quote!(
+                #[cfg(all(feature = #this_feature, feature = #that_feature))]
+                impl<'a> ::core::convert::TryFrom<&'a #this_name> for #that_name {
+                    type Error = NarrowingError;
+                    #[inline]
+                    fn try_from(value: &#this_name) -> Result<Self, Self::Error> {
+                        match value.highest_one() {
+                            Some(idx) if idx >= #that_bits_size => {
+                                Err(NarrowingError::Overflow(idx))
+                            }
+                            _ => Ok(prelude::UintConvert::convert_into(value).0),
+                        }
+                    }
+                }
+                #[cfg(all(feature = #this_feature, feature = #that_feature))]
+                impl ::core::convert::TryFrom<#this_name> for #that_name {
+                    type Error = NarrowingError;
+                    #[inline]
+                    fn try_from(value: #this_name) -> Result<Self, Self::Error> {
+                        <Self as ::core::convert::TryFrom<&#this_name>>::try_from(&value)
+                    }
+                }
+            )
+        };
+        let uint_convert = quote!(
             #[cfg(all(feature = #this_feature, feature = #that_feature))]
             impl prelude::UintConvert<#that_name> for #this_name {
                 #[inline]
@@ -90,7 +135,8 @@ impl UintConstructor {
                     #stmts
                 }
             }
-        )
+        );
+        quote!(#uint_convert #std_convert)
     }
 
     fn defun_priv_kernel(&self) {
@@ -99,7 +145,7 @@ impl UintConstructor {
         let part = quote!(
             /// Create a new fixed uint with a provided input.
             #[inline]
-            const fn new(data: #inner_type) -> Self {
+            pub(crate) const fn new(data: #inner_type) -> Self {
                 #name(data)
             }
             /// Get a reference of the inner data of the fixed uint.
@@ -125,7 +171,7 @@ impl UintConstructor {
         let unit_amount = &self.ts.unit_amount;
         let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let zero_padding = &utils::pure_uint_list_to_ts(
-            ::std::iter::repeat(0).take((self.info.unit_amount - 1) as usize),
+            ::core::iter::repeat(0).take((self.info.unit_amount - 1) as usize),
         );
         let one = quote!([1, #(#zero_padding),* ]);
         let part = quote!(
@@ -174,4 +220,45 @@ impl UintConstructor {
         );
         self.prelude(part);
     }
+
+    fn attach_error_for_conv_narrowing(&self) {
+        let part = quote!(
+            /// Error for convert a fixed uint into a smaller one.
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
+            pub enum NarrowingError {
+                Overflow(usize),
+            }
+            impl ::core::fmt::Display for NarrowingError {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        NarrowingError::Overflow(v) => {
+                            write!(f, "value is too big (highest one is at {})", v)
+                        }
+                    }
+                }
+            }
+        );
+        self.attach_common(part);
+        self.error(
+            "Narrowing",
+            "NarrowingError",
+            "failed to convert into a smaller fixed uint since {0}",
+        );
+    }
+
+    fn defun_pub_truncate_into(&self) {
+        let part = quote!(
+            /// Convert into another fixed uint, the higher bits are truncated.
+            #[inline]
+            pub fn truncate_into<T>(&self) -> T
+            where
+                Self: prelude::UintConvert<T>,
+            {
+                prelude::UintConvert::convert_into(self).0
+            }
+        );
+        self.defun(part);
+    }
 }
@@ -15,6 +15,7 @@ use quote::quote;
 impl UintConstructor {
     pub fn defun_priv_conv(&self) {
         self.defun_priv_from_primitive_uints();
+        self.defun_priv_into_primitive_uints();
     }
 
     fn defun_priv_from_primitive_uints(&self) {
@@ -56,4 +57,45 @@ impl UintConstructor {
             self.defun(part);
         }
     }
+
+    fn defun_priv_into_primitive_uints(&self) {
+        for prim_bits_size in &[8u64, 16, 32, 64, 128] {
+            let prim_type = utils::uint_suffix_to_ts(*prim_bits_size);
+            let func_name = utils::ident_to_ts(format!("_into_u{}", prim_bits_size).as_ref());
+            let part = match *prim_bits_size {
+                x if x <= self.info.unit_bits_size => quote!(
+                    #[inline]
+                    fn #func_name(&self) -> #prim_type {
+                        self.inner()[0] as #prim_type
+                    }
+                ),
+                _ => {
+                    if prim_bits_size % self.info.unit_bits_size != 0 {
+                        unreachable!();
+                    }
+                    let times = ::core::cmp::min(
+                        prim_bits_size / self.info.unit_bits_size,
+                        self.info.unit_amount,
+                    );
+                    let loop_times = &utils::pure_uint_list_to_ts(0..times);
+                    let loop_shift = &utils::pure_uint_list_to_ts(
+                        (0..times).map(|x| x * self.info.unit_bits_size),
+                    );
+                    let loop_prim_type = &vec![&prim_type; times as usize];
+                    quote!(
+                        #[inline]
+                        fn #func_name(&self) -> #prim_type {
+                            let inner = self.inner();
+                            let mut ret = 0;
+                            #({
+                                ret |= (inner[#loop_times] as #loop_prim_type) << #loop_shift;
+                            })*
+                            ret
+                        }
+                    )
+                }
+            };
+            self.defun(part);
+        }
+    }
 }
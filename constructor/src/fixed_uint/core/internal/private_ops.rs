@@ -20,6 +20,7 @@ impl UintConstructor {
         self.defun_priv_mul();
         self.defun_priv_full_mul();
         self.defun_priv_div_and_rem();
+        self.defun_priv_assign();
         self.defun_priv_pow();
         self.defun_priv_bitwise();
         self.defun_priv_not();
@@ -109,6 +110,34 @@ impl UintConstructor {
                 })*
                 (Self::new(ret), hi > 0)
             }
+            /// Calculate `self * other + addend`.
+            #[inline]
+            fn _mul_add_unit(&self, other: #unit_suffix, addend: #unit_suffix) -> (Self, bool) {
+                let mut ret: #inner_type = [0; #unit_amount];
+                let inner = self.inner();
+                let other = other as #double_unit_suffix;
+                let mut hi: #unit_suffix = addend;
+                #({
+                    let idx: usize = #loop_unit_amount;
+                    let val = inner[idx];
+                    if val == 0 {
+                        let ret_val = &mut ret[idx];
+                        *ret_val = hi;
+                        hi = 0;
+                    } else {
+                        let prod = (val as #loop_double_unit_suffix) * other;
+                        let lo = prod as #loop_unit_suffix;
+                        let ret_val = &mut ret[idx];
+                        let (lo, of) = lo.overflowing_add(hi);
+                        *ret_val = lo;
+                        hi = (prod >> #loop_unit_bits_size) as #loop_unit_suffix_clone;
+                        if of {
+                            hi += 1;
+                        }
+                    }
+                })*
+                (Self::new(ret), hi > 0)
+            }
             #[inline]
             fn _div_unit_with_rem(&self, other: #unit_suffix) -> (Self, #unit_suffix) {
                 // check this condition before call this function
@@ -174,6 +203,7 @@ impl UintConstructor {
         let unit_suffix = &self.ts.unit_suffix;
         let inner_type = &self.ts.inner_type;
         let mut loop_part = quote!();
+        let mut loop_part_assign = quote!();
         for i in 0..self.info.unit_amount {
             let i = utils::pure_uint_to_ts(i);
             let loop_part_tmp = quote!(
@@ -182,19 +212,34 @@ impl UintConstructor {
                     // The carry only can be one.
                     let (val_o, of_o) = #unit_suffix::overflowing_add(val_n, 1);
                     unsafe {
-                        ::std::ptr::write(ret_ptr.offset(#i), val_o);
+                        ::core::ptr::write(ret_ptr.offset(#i), val_o);
                     }
                     // Can not overflow twice.
                     of_n || of_o
                 } else {
                     let (val_n, of_n) = #unit_suffix::overflowing_add(lhs[#i], rhs[#i]);
                     unsafe {
-                        ::std::ptr::write(ret_ptr.offset(#i), val_n);
+                        ::core::ptr::write(ret_ptr.offset(#i), val_n);
                     }
                     of_n
                 };
             );
             loop_part = quote!(#loop_part #loop_part_tmp);
+            let loop_part_tmp = quote!(
+                of = if of {
+                    let (val_n, of_n) = #unit_suffix::overflowing_add(lhs[#i], rhs[#i]);
+                    // The carry only can be one.
+                    let (val_o, of_o) = #unit_suffix::overflowing_add(val_n, 1);
+                    lhs[#i] = val_o;
+                    // Can not overflow twice.
+                    of_n || of_o
+                } else {
+                    let (val_n, of_n) = #unit_suffix::overflowing_add(lhs[#i], rhs[#i]);
+                    lhs[#i] = val_n;
+                    of_n
+                };
+            );
+            loop_part_assign = quote!(#loop_part_assign #loop_part_tmp);
         }
         let part = quote!(
             #[inline]
@@ -202,12 +247,20 @@ impl UintConstructor {
                 let lhs = self.inner();
                 let rhs = other.inner();
                 let mut ret: #inner_type =
-                    unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                    unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                 let ret_ptr = &mut ret as *mut #inner_type as *mut #unit_suffix;
                 let mut of = false;
                 #loop_part;
                 (Self::new(ret), of)
             }
+            #[inline]
+            fn _add_assign(&mut self, other: &Self) -> bool {
+                let lhs = self.mut_inner();
+                let rhs = other.inner();
+                let mut of = false;
+                #loop_part_assign;
+                of
+            }
         );
         self.defun(part);
     }
@@ -216,6 +269,7 @@ impl UintConstructor {
         let inner_type = &self.ts.inner_type;
         let unit_suffix = &self.ts.unit_suffix;
         let mut loop_part = quote!();
+        let mut loop_part_assign = quote!();
         for i in 0..self.info.unit_amount {
             let i = utils::pure_uint_to_ts(i);
             let loop_part_tmp = quote!(
@@ -224,19 +278,34 @@ impl UintConstructor {
                     // The carry only can be one.
                     let (val_o, of_o) = #unit_suffix::overflowing_sub(val_n, 1);
                     unsafe {
-                        ::std::ptr::write(ret_ptr.offset(#i), val_o);
+                        ::core::ptr::write(ret_ptr.offset(#i), val_o);
                     }
                     // Can not overflow twice.
                     of_n || of_o
                 } else {
                     let (val_n, of_n) = #unit_suffix::overflowing_sub(lhs[#i], rhs[#i]);
                     unsafe {
-                        ::std::ptr::write(ret_ptr.offset(#i), val_n);
+                        ::core::ptr::write(ret_ptr.offset(#i), val_n);
                     }
                     of_n
                 };
             );
             loop_part = quote!(#loop_part #loop_part_tmp);
+            let loop_part_tmp = quote!(
+                of = if of {
+                    let (val_n, of_n) = #unit_suffix::overflowing_sub(lhs[#i], rhs[#i]);
+                    // The carry only can be one.
+                    let (val_o, of_o) = #unit_suffix::overflowing_sub(val_n, 1);
+                    lhs[#i] = val_o;
+                    // Can not overflow twice.
+                    of_n || of_o
+                } else {
+                    let (val_n, of_n) = #unit_suffix::overflowing_sub(lhs[#i], rhs[#i]);
+                    lhs[#i] = val_n;
+                    of_n
+                };
+            );
+            loop_part_assign = quote!(#loop_part_assign #loop_part_tmp);
         }
         let part = quote!(
             #[inline]
@@ -244,12 +313,20 @@ impl UintConstructor {
                 let lhs = self.inner();
                 let rhs = other.inner();
                 let mut ret: #inner_type =
-                    unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                    unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                 let ret_ptr = &mut ret as *mut #inner_type as *mut #unit_suffix;
                 let mut of = false;
                 #loop_part;
                 (Self::new(ret), of)
             }
+            #[inline]
+            fn _sub_assign(&mut self, other: &Self) -> bool {
+                let lhs = self.mut_inner();
+                let rhs = other.inner();
+                let mut of = false;
+                #loop_part_assign;
+                of
+            }
         );
         self.defun(part);
     }
@@ -621,14 +698,12 @@ impl UintConstructor {
                         minuend_tmp
                     };
                     // could not overflow
-                    let (copy_new, _) = copy._sub(&minuend);
-                    copy = copy_new;
+                    copy._sub_assign(&minuend);
                 }
                 let mut more: #unit_suffix = 0;
                 while copy >= *other {
                     // could not overflow
-                    let (copy_new, _) = copy._sub(other);
-                    copy = copy_new;
+                    copy._sub_assign(other);
                     more += 1;
                 }
                 {
@@ -675,6 +750,31 @@ impl UintConstructor {
         self.defun(part);
     }
 
+    // In-place variants for the operations which have no dedicated in-place kernels.
+    fn defun_priv_assign(&self) {
+        let part = quote!(
+            #[inline]
+            fn _mul_assign(&mut self, other: &Self) -> bool {
+                let (ret, of) = self._mul(other);
+                *self = ret;
+                of
+            }
+            #[inline]
+            fn _div_assign(&mut self, other: &Self) -> bool {
+                let (ret, of) = self._div(other);
+                *self = ret;
+                of
+            }
+            #[inline]
+            fn _rem_assign(&mut self, other: &Self) -> bool {
+                let (ret, of) = self._rem(other);
+                *self = ret;
+                of
+            }
+        );
+        self.defun(part);
+    }
+
     fn defun_priv_pow(&self) {
         let unit_bits_size = &self.ts.unit_bits_size;
         let loop_unit_amount_rev = &utils::pure_uint_list_to_ts((0..self.info.unit_amount).rev());
@@ -753,7 +853,7 @@ impl UintConstructor {
         let part = quote!(
             #[inline]
             fn _bitand(&self, rhs: &Self) -> Self {
-                let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                 let inner = self.inner();
                 let rhs = rhs.inner();
                 #({
@@ -763,8 +863,17 @@ impl UintConstructor {
                 Self::new(ret)
             }
             #[inline]
+            fn _bitand_assign(&mut self, rhs: &Self) {
+                let inner = self.mut_inner();
+                let rhs = rhs.inner();
+                #({
+                    let idx = #loop_unit_amount;
+                    inner[idx] &= rhs[idx];
+                })*
+            }
+            #[inline]
             fn _bitor(&self, rhs: &Self) -> Self {
-                let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                 let inner = self.inner();
                 let rhs = rhs.inner();
                 #({
@@ -774,8 +883,17 @@ impl UintConstructor {
                 Self::new(ret)
             }
             #[inline]
+            fn _bitor_assign(&mut self, rhs: &Self) {
+                let inner = self.mut_inner();
+                let rhs = rhs.inner();
+                #({
+                    let idx = #loop_unit_amount;
+                    inner[idx] |= rhs[idx];
+                })*
+            }
+            #[inline]
             fn _bitxor(&self, rhs: &Self) -> Self {
-                let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                 let inner = self.inner();
                 let rhs = rhs.inner();
                 #({
@@ -784,6 +902,15 @@ impl UintConstructor {
                 })*
                 Self::new(ret)
             }
+            #[inline]
+            fn _bitxor_assign(&mut self, rhs: &Self) {
+                let inner = self.mut_inner();
+                let rhs = rhs.inner();
+                #({
+                    let idx = #loop_unit_amount;
+                    inner[idx] ^= rhs[idx];
+                })*
+            }
         );
         self.defun(part);
     }
@@ -794,7 +921,7 @@ impl UintConstructor {
         let part = quote!(
             #[inline]
             fn _not(&self) -> Self {
-                let mut ret: #inner_type = unsafe { ::std::mem::MaybeUninit::uninit().assume_init() };
+                let mut ret: #inner_type = unsafe { ::core::mem::MaybeUninit::uninit().assume_init() };
                 let inner = self.inner();
                 #({
                     let idx = #loop_unit_amount;
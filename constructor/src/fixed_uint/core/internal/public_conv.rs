@@ -16,6 +16,9 @@ impl UintConstructor {
     pub fn defun_pub_conv(&self) {
         self.defun_pub_conv_from_slice();
         self.defun_pub_conv_into_slice();
+        self.defun_pub_conv_into_primitive();
+        self.attach_error_for_conv_from_float();
+        self.defun_pub_conv_float();
         self.attach_error_for_conv_from_str();
         self.defun_pub_conv_from_bin_str();
         self.defun_pub_conv_from_oct_str_dict();
@@ -24,26 +27,37 @@ impl UintConstructor {
         self.defun_pub_conv_from_hex_str();
         self.defun_pub_conv_from_dec_str_dict();
         self.defun_pub_conv_from_dec_str();
+        self.defun_pub_conv_from_str_radix();
+        self.defun_pub_conv_into_digits();
+        self.defun_pub_conv_to_str_radix();
+        self.attach_struct_for_conv_units();
+        self.defun_pub_conv_units();
+        self.defun_pub_conv_from_sci_str();
     }
 
     fn attach_error_for_conv_slice(&self, conv_type: &str, type_explain: &str) {
-        let error_item = utils::ident_to_ts(format!("{}Slice", conv_type).as_ref());
-        let inner_error_name = utils::ident_to_ts(format!("{}SliceError", conv_type).as_ref());
+        let error_item = format!("{}Slice", conv_type);
+        let inner_error_name = format!("{}SliceError", conv_type);
+        let inner_error_ts = utils::ident_to_ts(&inner_error_name);
         let error_explain = format!("failed to convert {} slice since {{0}}", type_explain);
         let part = quote!(
             /// Error for parse from slice.
-            #[derive(Debug, Error)]
-            pub enum #inner_error_name {
-                #[error("invalid length: {0}")]
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
+            pub enum #inner_error_ts {
                 InvalidLength(usize),
             }
+            impl ::core::fmt::Display for #inner_error_ts {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        #inner_error_ts::InvalidLength(v) => write!(f, "invalid length: {}", v),
+                    }
+                }
+            }
         );
         self.attach_common(part);
-        let part = quote!(
-            #[error(#error_explain)]
-            #error_item(#[from] #inner_error_name),
-        );
-        self.error(part);
+        self.error(&error_item, &inner_error_name, &error_explain);
     }
 
     fn defun_pub_conv_from_slice(&self) {
@@ -254,26 +268,238 @@ impl UintConstructor {
         self.defun(part);
     }
 
+    fn defun_pub_conv_into_primitive(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            /// Convert into `u64`, the higher bits are truncated.
+            #[inline]
+            pub fn low_u64(&self) -> u64 {
+                self._into_u64()
+            }
+            /// Convert into `u128`, the higher bits are truncated.
+            #[inline]
+            pub fn low_u128(&self) -> u128 {
+                self._into_u128()
+            }
+            /// Convert into `u64`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number is larger than `u64::max_value()`.
+            #[inline]
+            pub fn as_u64(&self) -> u64 {
+                match self.highest_one() {
+                    Some(idx) if idx >= 64 => {
+                        panic!(
+                            "{}: attempt to convert into u64 with overflow",
+                            stringify!(#name)
+                        )
+                    }
+                    _ => self._into_u64(),
+                }
+            }
+            /// Convert into `usize`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number is larger than `usize::max_value()`.
+            #[inline]
+            pub fn as_usize(&self) -> usize {
+                match self.highest_one() {
+                    Some(idx) if idx >= ::core::mem::size_of::<usize>() * 8 => {
+                        panic!(
+                            "{}: attempt to convert into usize with overflow",
+                            stringify!(#name)
+                        )
+                    }
+                    _ => self._into_u128() as usize,
+                }
+            }
+        );
+        self.defun(part);
+    }
+
+    fn attach_error_for_conv_from_float(&self) {
+        let part = quote!(
+            /// Error for convert from floating-point numbers.
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
+            pub enum FromFloatError {
+                NaN,
+                Negative(f64),
+                Overflow(f64),
+            }
+            impl ::core::fmt::Display for FromFloatError {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        FromFloatError::NaN => write!(f, "not a number"),
+                        FromFloatError::Negative(v) => write!(f, "negative value: {}", v),
+                        FromFloatError::Overflow(v) => write!(f, "value is too big: {}", v),
+                    }
+                }
+            }
+        );
+        self.attach_common(part);
+        self.error(
+            "FromFloat",
+            "FromFloatError",
+            "failed to convert from float since {0}",
+        );
+    }
+
+    fn defun_pub_conv_float(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let bits_size = &self.ts.bits_size;
+        let part = quote!(
+            /// Convert into `f64` with a sticky bit, which is set if any lower bits are discarded.
+            ///
+            /// The sticky bit only makes sense when the number has more than 55 bits.
+            #[inline]
+            fn _into_f64(&self, sticky: bool) -> f64 {
+                let bits = #bits_size - self.leading_zeros();
+                if bits <= 128 {
+                    (self._into_u128() | u128::from(sticky)) as f64
+                } else if bits > 1024 {
+                    f64::INFINITY
+                } else {
+                    let shift = bits - 128;
+                    let sticky = sticky || self.trailing_zeros() < shift;
+                    let val = self._ushr(u128::from(shift))._into_u128() | u128::from(sticky);
+                    val as f64 * f64::from_bits(u64::from(1023 + shift) << 52)
+                }
+            }
+            /// Convert into `f64`, rounding to nearest.
+            ///
+            /// Returns infinity if the number is too big.
+            #[inline]
+            pub fn to_f64(&self) -> f64 {
+                self._into_f64(false)
+            }
+            /// Convert into `f32`, rounding to nearest.
+            ///
+            /// Returns infinity if the number is too big.
+            #[inline]
+            pub fn to_f32(&self) -> f32 {
+                if #bits_size - self.leading_zeros() > 128 {
+                    f32::INFINITY
+                } else {
+                    self._into_u128() as f32
+                }
+            }
+            /// Calculate `self / denom` as `f64`, rounding to nearest.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `denom` is zero.
+            #[inline]
+            pub fn ratio_to_f64(&self, denom: &Self) -> f64 {
+                let (quotient, mut remainder) = self.complete_div(denom);
+                if #bits_size - quotient.leading_zeros() >= 66 {
+                    return quotient._into_f64(!remainder.is_zero());
+                }
+                // calculate the fraction bits until there are enough significant bits
+                let mut mantissa = quotient._into_u128();
+                let mut frac_bits = 0u32;
+                while mantissa.leading_zeros() > 128 - 66 && !remainder.is_zero() {
+                    let carry = remainder.leading_zeros() == 0;
+                    remainder = remainder._ushl(1);
+                    mantissa <<= 1;
+                    if carry || remainder >= *denom {
+                        remainder = remainder._sub(denom).0;
+                        mantissa |= 1;
+                    }
+                    frac_bits += 1;
+                }
+                let mut ret = (mantissa | u128::from(!remainder.is_zero())) as f64;
+                while frac_bits > 0 {
+                    let shift = ::core::cmp::min(frac_bits, 1000);
+                    ret *= f64::from_bits(u64::from(1023 - shift) << 52);
+                    frac_bits -= shift;
+                }
+                ret
+            }
+            /// Convert from `f64`, the fractional part is truncated.
+            ///
+            /// Returns an error if the input is NaN, negative or too big.
+            #[inline]
+            pub fn try_from_f64(val: f64) -> Result<Self, #error_name> {
+                if val.is_nan() {
+                    return Err(FromFloatError::NaN.into());
+                } else if val < 0.0 {
+                    return Err(FromFloatError::Negative(val).into());
+                } else if val.is_infinite() {
+                    return Err(FromFloatError::Overflow(val).into());
+                } else if val < 1.0 {
+                    return Ok(Self::zero());
+                }
+                let bits = val.to_bits();
+                let exp = ((bits >> 52) & 0x7ff) as u32;
+                let mantissa = (bits & 0xf_ffff_ffff_ffff) | 0x10_0000_0000_0000;
+                if exp < 1075 {
+                    Ok(Self::_from_u64(mantissa >> (1075 - exp)))
+                } else if exp - 1075 + 53 > #bits_size {
+                    Err(FromFloatError::Overflow(val).into())
+                } else {
+                    Ok(Self::_from_u64(mantissa)._ushl(u128::from(exp - 1075)))
+                }
+            }
+            /// Convert from `f64`, the fractional part is truncated.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the input is NaN, negative or too big.
+            #[inline]
+            pub fn from_f64(val: f64) -> Self {
+                match Self::try_from_f64(val) {
+                    Ok(ret) => ret,
+                    Err(_) => panic!(
+                        "{}: attempt to convert from an invalid float {}",
+                        stringify!(#name),
+                        val
+                    ),
+                }
+            }
+        );
+        self.defun(part);
+    }
+
     fn attach_error_for_conv_from_str(&self) {
         let part = quote!(
             /// Error for parse from string.
-            #[derive(Debug, Error)]
+            #[derive(Debug)]
+            #[cfg_attr(feature = "std", derive(Error))]
             pub enum FromStrError {
-                #[error("invalid character code `{chr}` at {idx}")]
                 InvalidCharacter { chr: u8, idx: usize },
-                #[error("invalid length: {0}")]
                 InvalidLength(usize),
-                #[error("number is too big (length is {0})")]
                 Overflow(usize),
+                ExcessPrecision(u32),
+            }
+            impl ::core::fmt::Display for FromStrError {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self {
+                        FromStrError::InvalidCharacter { chr, idx } => {
+                            write!(f, "invalid character code `{}` at {}", chr, idx)
+                        }
+                        FromStrError::InvalidLength(v) => write!(f, "invalid length: {}", v),
+                        FromStrError::Overflow(v) => {
+                            write!(f, "number is too big (length is {})", v)
+                        }
+                        FromStrError::ExcessPrecision(v) => {
+                            write!(f, "too many fractional digits (at most {})", v)
+                        }
+                    }
+                }
             }
         );
         self.attach_common(part);
-        #[rustfmt::skip]
-        let part = quote!(
-            #[error("failed to parse from string since {0}")]
-            FromStr(#[from] FromStrError),
+        self.error(
+            "FromStr",
+            "FromStrError",
+            "failed to parse from string since {0}",
         );
-        self.error(part);
     }
 
     fn defun_pub_conv_from_bin_str(&self) {
@@ -528,14 +754,23 @@ impl UintConstructor {
     }
 
     fn defun_pub_conv_from_dec_str(&self) {
-        let name = &self.ts.name;
         let error_name = &self.ts.error_name;
         let utils_name = &self.ts.utils_name;
-        let char_amount_max = utils::pure_uint_to_ts(if self.info.bits_size % 10 == 0 {
-            self.info.bits_size / 10
-        } else {
-            (f64::from(self.info.bits_size as u32) / 10f64.log2()).ceil() as u64
-        });
+        let unit_suffix = &self.ts.unit_suffix;
+        let chunk_width = {
+            // the amount of decimal digits which always fit a unit
+            let unit_max = u128::MAX >> (128 - self.info.unit_bits_size);
+            let mut width = 0;
+            let mut scale = Some(10u128);
+            while let Some(x) = scale.filter(|x| *x <= unit_max) {
+                width += 1;
+                scale = x.checked_mul(10);
+            }
+            utils::pure_uint_to_ts(width)
+        };
+        let char_amount_max = utils::pure_uint_to_ts(
+            (f64::from(self.info.bits_size as u32) / 10f64.log2()).ceil() as u64,
+        );
         let part = quote!(
             /// Convert from a decimal string.
             #[inline]
@@ -546,18 +781,84 @@ impl UintConstructor {
                 } else if len != 1 && input.as_bytes()[0] == b'0' {
                     return Err(FromStrError::InvalidCharacter { chr: b'0', idx: 0 }.into());
                 }
+                // parse the digits chunk by chunk, each chunk fits a unit
+                let mut ret = Self::zero();
+                let mut start = 0;
+                let mut end = match len % #chunk_width {
+                    0 => #chunk_width,
+                    x => x,
+                };
+                while start < len {
+                    let mut chunk: #unit_suffix = 0;
+                    let mut scale: #unit_suffix = 1;
+                    for (idx, chr) in input.bytes().enumerate().take(end).skip(start) {
+                        let v = #utils_name::DICT_DEC[usize::from(chr)];
+                        if v == #utils_name::DICT_DEC_ERROR {
+                            return Err(FromStrError::InvalidCharacter { chr, idx }.into());
+                        }
+                        chunk = chunk * 10 + #unit_suffix::from(v);
+                        scale *= 10;
+                    }
+                    let (ret_new, of) = ret._mul_add_unit(scale, chunk);
+                    if of {
+                        return Err(FromStrError::Overflow(len).into());
+                    }
+                    ret = ret_new;
+                    start = end;
+                    end += #chunk_width;
+                }
+                Ok(ret)
+            }
+        );
+        self.defun(part);
+    }
+
+    fn defun_pub_conv_from_str_radix(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let unit_suffix = &self.ts.unit_suffix;
+        let part = quote!(
+            /// Convert from a string in the given radix.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            #[inline]
+            pub fn from_str_radix(input: &str, radix: u32) -> Result<Self, #error_name> {
+                match radix {
+                    2 => return Self::from_bin_str(input),
+                    8 => return Self::from_oct_str(input),
+                    10 => return Self::from_dec_str(input),
+                    16 => return Self::from_hex_str(input),
+                    3..=36 => {}
+                    _ => panic!(
+                        "{}: from_str_radix_int: must lie in the range `[2, 36]` - found {}",
+                        stringify!(#name),
+                        radix
+                    ),
+                }
+                let len = input.len();
+                if len == 0 {
+                    return Err(FromStrError::InvalidLength(len).into());
+                } else if len != 1 && input.as_bytes()[0] == b'0' {
+                    return Err(FromStrError::InvalidCharacter { chr: b'0', idx: 0 }.into());
+                }
                 let mut ret = Self::zero();
                 for (idx, chr) in input.bytes().enumerate() {
-                    let v = #utils_name::DICT_DEC[usize::from(chr)];
-                    if v == #utils_name::DICT_DEC_ERROR {
+                    let v = match chr {
+                        b'0'..=b'9' => chr - b'0',
+                        b'a'..=b'z' => chr - b'a' + 10,
+                        b'A'..=b'Z' => chr - b'A' + 10,
+                        _ => return Err(FromStrError::InvalidCharacter { chr, idx }.into()),
+                    };
+                    if u32::from(v) >= radix {
                         return Err(FromStrError::InvalidCharacter { chr, idx }.into());
                     }
-                    let (ret_new, of) = ret._mul_unit(10);
+                    let (ret_new, of) = ret._mul_unit(radix as #unit_suffix);
                     if of {
                         return Err(FromStrError::Overflow(len).into());
                     }
-                    let u = #name::from(v);
-                    let (ret_new, of) = ret_new._add(&u);
+                    let (ret_new, of) = ret_new._add(&Self::from(v));
                     if of {
                         return Err(FromStrError::Overflow(len).into());
                     }
@@ -568,4 +869,402 @@ impl UintConstructor {
         );
         self.defun(part);
     }
+
+    fn defun_pub_conv_into_digits(&self) {
+        let name = &self.ts.name;
+        let bits_size = &self.ts.bits_size;
+        let unit_bits_size = &self.ts.unit_bits_size;
+        let unit_amount = &self.ts.unit_amount;
+        let unit_suffix = &self.ts.unit_suffix;
+        let part = quote!(
+            /// An iterator over the digits of a fixed uint, which are stored in a buffer on stack.
+            struct Digits {
+                buf: [u8; #bits_size],
+                start: usize,
+                end: usize,
+            }
+            impl ::core::iter::Iterator for Digits {
+                type Item = u8;
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.start < self.end {
+                        self.start += 1;
+                        Some(self.buf[self.start - 1])
+                    } else {
+                        None
+                    }
+                }
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let len = self.end - self.start;
+                    (len, Some(len))
+                }
+            }
+            impl ::core::iter::DoubleEndedIterator for Digits {
+                #[inline]
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    if self.start < self.end {
+                        self.end -= 1;
+                        Some(self.buf[self.end])
+                    } else {
+                        None
+                    }
+                }
+            }
+            impl ::core::iter::ExactSizeIterator for Digits {}
+        );
+        self.implt(part);
+        let part = quote!(
+            /// Write the digits in the given radix into the buffer,
+            /// the least significant digit comes first.
+            ///
+            /// Returns the amount of the digits.
+            #[inline]
+            fn _write_digits_le(&self, buf: &mut [u8; #bits_size], radix: u32) -> usize {
+                if !(2..=256).contains(&radix) {
+                    panic!(
+                        "{}: to_digits: radix must lie in the range `[2, 256]` - found {}",
+                        stringify!(#name),
+                        radix
+                    );
+                }
+                let mut len = 0;
+                if radix.is_power_of_two() {
+                    // slice the bits directly
+                    let digit_bits = radix.trailing_zeros() as usize;
+                    let mask = (radix - 1) as #unit_suffix;
+                    let total_bits = #bits_size - self.leading_zeros() as usize;
+                    let inner = self.inner();
+                    let mut pos = 0;
+                    while pos < total_bits {
+                        let idx = pos / #unit_bits_size;
+                        let offset = pos % #unit_bits_size;
+                        let mut val = inner[idx] >> offset;
+                        if offset + digit_bits > #unit_bits_size && idx + 1 < #unit_amount {
+                            val |= inner[idx + 1] << (#unit_bits_size - offset);
+                        }
+                        buf[len] = (val & mask) as u8;
+                        len += 1;
+                        pos += digit_bits;
+                    }
+                } else {
+                    // the largest power of the radix which fits a unit
+                    let radix = radix as #unit_suffix;
+                    let mut divisor = radix;
+                    let mut width = 1;
+                    while let Some(next) = divisor.checked_mul(radix) {
+                        divisor = next;
+                        width += 1;
+                    }
+                    let mut val = self.clone();
+                    loop {
+                        let (quotient, mut rem) = val._div_unit_with_rem(divisor);
+                        if quotient.is_zero() {
+                            while rem > 0 {
+                                buf[len] = (rem % radix) as u8;
+                                rem /= radix;
+                                len += 1;
+                            }
+                            break;
+                        }
+                        for _ in 0..width {
+                            buf[len] = (rem % radix) as u8;
+                            rem /= radix;
+                            len += 1;
+                        }
+                        val = quotient;
+                    }
+                }
+                if len == 0 {
+                    buf[0] = 0;
+                    len = 1;
+                }
+                len
+            }
+            /// Calculate the digits in the given radix, the least significant digit comes first.
+            #[inline]
+            fn _into_digits(&self, radix: u32) -> Digits {
+                let mut buf = [0u8; #bits_size];
+                let len = self._write_digits_le(&mut buf, radix);
+                Digits {
+                    buf,
+                    start: 0,
+                    end: len,
+                }
+            }
+            /// Return an iterator over the digits in the given radix,
+            /// the least significant digit comes first.
+            ///
+            /// The digits are calculated on stack, no heap allocation is required.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 256.
+            #[inline]
+            pub fn to_digits_le(
+                &self,
+                radix: u32,
+            ) -> impl ::core::iter::DoubleEndedIterator<Item = u8> + ::core::iter::ExactSizeIterator
+            {
+                self._into_digits(radix)
+            }
+            /// Return an iterator over the digits in the given radix,
+            /// the most significant digit comes first.
+            ///
+            /// The digits are calculated on stack, no heap allocation is required.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 256.
+            #[inline]
+            pub fn to_digits_be(
+                &self,
+                radix: u32,
+            ) -> impl ::core::iter::DoubleEndedIterator<Item = u8> + ::core::iter::ExactSizeIterator
+            {
+                self._into_digits(radix).rev()
+            }
+        );
+        self.defun(part);
+    }
+
+    fn defun_pub_conv_to_str_radix(&self) {
+        let name = &self.ts.name;
+        let bits_size = &self.ts.bits_size;
+        let part = quote!(
+            /// Convert into a string in the given radix, the letters are in lowercase.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn to_str_radix(&self, radix: u32) -> ::alloc::string::String {
+                if !(2..=36).contains(&radix) {
+                    panic!(
+                        "{}: to_str_radix: radix must lie in the range `[2, 36]` - found {}",
+                        stringify!(#name),
+                        radix
+                    );
+                }
+                self._into_digits(radix)
+                    .rev()
+                    .map(|digit| {
+                        if digit < 10 {
+                            char::from(b'0' + digit)
+                        } else {
+                            char::from(b'a' + digit - 10)
+                        }
+                    })
+                    .collect()
+            }
+            /// Write the decimal digits into the buffer from the beginning, and return them as a
+            /// string slice, without any allocation.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if the buffer is too short to hold all digits.
+            #[inline]
+            pub fn write_dec_to<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+                let mut digits = [0u8; #bits_size];
+                let len = self._write_digits_le(&mut digits, 10);
+                if buf.len() < len {
+                    panic!(
+                        "{}: write_dec_to: the buffer is too short ({} < {})",
+                        stringify!(#name),
+                        buf.len(),
+                        len
+                    );
+                }
+                for (dst, src) in buf.iter_mut().zip(digits[..len].iter().rev()) {
+                    *dst = b'0' + src;
+                }
+                // all digits are ASCII characters
+                ::core::str::from_utf8(&buf[..len]).unwrap()
+            }
+        );
+        self.defun(part);
+    }
+
+    fn attach_struct_for_conv_units(&self) {
+        let part = quote!(
+            /// Options for formatting a fixed uint as a decimal number with fixed decimals.
+            #[derive(Debug, Clone, Default, PartialEq, Eq)]
+            pub struct FormatUnitsOptions {
+                /// Remove the trailing zeros in the fractional part.
+                pub trim_zeros: bool,
+                /// Separate every three digits in the integer part with this character.
+                pub thousands_separator: Option<char>,
+                /// Keep at most this amount of fractional digits, the rest are truncated.
+                pub max_fraction_digits: Option<usize>,
+            }
+        );
+        self.attach_common(part);
+    }
+
+    fn defun_pub_conv_units(&self) {
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            /// Format as a decimal number with fixed decimals, for example, `1234.5678`.
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn format_units(&self, decimals: u32) -> ::alloc::string::String {
+                self.format_units_with(decimals, &FormatUnitsOptions::default())
+            }
+            /// Format as a decimal number with fixed decimals and the provided options.
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn format_units_with(
+                &self,
+                decimals: u32,
+                options: &FormatUnitsOptions,
+            ) -> ::alloc::string::String {
+                let digits = ::alloc::string::ToString::to_string(self);
+                let decimals = decimals as usize;
+                let (int_part, frac_part) = if digits.len() > decimals {
+                    let (int_part, frac_part) = digits.split_at(digits.len() - decimals);
+                    (int_part, ::alloc::borrow::ToOwned::to_owned(frac_part))
+                } else {
+                    ("0", "0".repeat(decimals - digits.len()) + &digits)
+                };
+                let mut frac_part = frac_part.as_str();
+                if let Some(max) = options.max_fraction_digits {
+                    if frac_part.len() > max {
+                        frac_part = &frac_part[..max];
+                    }
+                }
+                if options.trim_zeros {
+                    frac_part = frac_part.trim_end_matches('0');
+                }
+                let mut ret = ::alloc::string::String::with_capacity(
+                    int_part.len() * 2 + frac_part.len() + 1,
+                );
+                if let Some(sep) = options.thousands_separator {
+                    for (idx, chr) in int_part.chars().enumerate() {
+                        if idx > 0 && (int_part.len() - idx) % 3 == 0 {
+                            ret.push(sep);
+                        }
+                        ret.push(chr);
+                    }
+                } else {
+                    ret.push_str(int_part);
+                }
+                if !frac_part.is_empty() {
+                    ret.push('.');
+                    ret.push_str(frac_part);
+                }
+                ret
+            }
+            /// Parse a decimal number with fixed decimals, for example, `0.25`.
+            ///
+            /// Returns an error if there are more fractional digits than the decimals,
+            /// except for the trailing zeros.
+            #[inline]
+            pub fn parse_units(input: &str, decimals: u32) -> Result<Self, #error_name> {
+                let (int_part, frac_part) = if let Some(idx) = input.find('.') {
+                    (&input[..idx], &input[(idx + 1)..])
+                } else {
+                    (input, "")
+                };
+                if int_part.is_empty() && frac_part.is_empty() {
+                    return Err(FromStrError::InvalidLength(input.len()).into());
+                }
+                let frac_part = frac_part.trim_end_matches('0');
+                if frac_part.len() > decimals as usize {
+                    return Err(FromStrError::ExcessPrecision(decimals).into());
+                }
+                let ten = Self::_from_u8(10);
+                let mut ret = Self::zero();
+                let int_part = int_part.trim_start_matches('0');
+                if !int_part.is_empty() {
+                    let (scale, of) = ten._pow(decimals);
+                    if of {
+                        return Err(FromStrError::Overflow(input.len()).into());
+                    }
+                    let val = Self::from_dec_str(int_part)?;
+                    let (val, of) = val._mul(&scale);
+                    if of {
+                        return Err(FromStrError::Overflow(input.len()).into());
+                    }
+                    ret = val;
+                }
+                let frac_trimmed = frac_part.trim_start_matches('0');
+                if !frac_trimmed.is_empty() {
+                    // the scale is less than the scale of the integer part
+                    let (scale, of) = ten._pow(decimals - frac_part.len() as u32);
+                    let val = Self::from_dec_str(frac_trimmed)?;
+                    let (val, of_mul) = val._mul(&scale);
+                    if of || of_mul {
+                        return Err(FromStrError::Overflow(input.len()).into());
+                    }
+                    let (val, of) = ret._add(&val);
+                    if of {
+                        return Err(FromStrError::Overflow(input.len()).into());
+                    }
+                    ret = val;
+                }
+                Ok(ret)
+            }
+        );
+        self.defun(part);
+    }
+
+    fn defun_pub_conv_from_sci_str(&self) {
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            /// Convert from a decimal string in scientific notation, for example, `1.5e18`.
+            ///
+            /// The exponent could be negative, but only the numbers which could be represented
+            /// exactly are accepted.
+            #[inline]
+            pub fn from_sci_str(input: &str) -> Result<Self, #error_name> {
+                let idx = if let Some(idx) = input.find(|chr| chr == 'e' || chr == 'E') {
+                    idx
+                } else {
+                    return Self::parse_units(input, 0);
+                };
+                let mantissa = &input[..idx];
+                let mut exp_str = &input[(idx + 1)..];
+                let mut exp_idx = idx + 1;
+                let negative = exp_str.starts_with('-');
+                if negative || exp_str.starts_with('+') {
+                    exp_str = &exp_str[1..];
+                    exp_idx += 1;
+                }
+                if exp_str.is_empty() {
+                    return Err(FromStrError::InvalidLength(input.len()).into());
+                }
+                let mut exp = 0u32;
+                for (i, chr) in exp_str.bytes().enumerate() {
+                    if !chr.is_ascii_digit() {
+                        let idx = exp_idx + i;
+                        return Err(FromStrError::InvalidCharacter { chr, idx }.into());
+                    }
+                    exp = exp
+                        .checked_mul(10)
+                        .and_then(|x| x.checked_add(u32::from(chr - b'0')))
+                        .ok_or_else(|| FromStrError::Overflow(input.len()))?;
+                }
+                if !negative {
+                    return Self::parse_units(mantissa, exp);
+                }
+                // a negative exponent is accepted only if the result is still an integer
+                let value = Self::parse_units(mantissa, 0)?;
+                let (power, overflow) = Self::_from_u8(10)._pow(exp);
+                if overflow {
+                    if value.is_zero() {
+                        return Ok(value);
+                    }
+                    return Err(FromStrError::ExcessPrecision(0).into());
+                }
+                let (quotient, remainder) = value._div_with_rem(&power).unwrap();
+                if remainder.is_zero() {
+                    Ok(quotient)
+                } else {
+                    Err(FromStrError::ExcessPrecision(0).into())
+                }
+            }
+        );
+        self.defun(part);
+    }
 }
@@ -14,6 +14,7 @@ use quote::quote;
 impl UintConstructor {
     pub fn defun_pub_math(&self) {
         self.defun_pub_gcd();
+        self.defun_pub_ilog10();
     }
 
     fn defun_pub_gcd(&self) {
@@ -32,7 +33,7 @@ impl UintConstructor {
                 let mut n = other.clone();
 
                 // find common factors of 2
-                let shift = ::std::cmp::min(m.trailing_zeros(), n.trailing_zeros());
+                let shift = ::core::cmp::min(m.trailing_zeros(), n.trailing_zeros());
 
                 // divide m and n by 2 until odd
                 // m inside loop
@@ -41,7 +42,7 @@ impl UintConstructor {
                 while !m.is_zero() {
                     m >>= m.trailing_zeros();
                     if n > m {
-                        ::std::mem::swap(&mut n, &mut m)
+                        ::core::mem::swap(&mut n, &mut m)
                     }
                     m -= &n;
                 }
@@ -51,4 +52,48 @@ impl UintConstructor {
         );
         self.defun(part);
     }
+
+    fn defun_pub_ilog10(&self) {
+        let name = &self.ts.name;
+        let part = quote!(
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is zero.
+            #[inline]
+            pub fn checked_ilog10(&self) -> Option<u32> {
+                let bits = u64::from(self.highest_one()? as u32) + 1;
+                // `1233 / 4096` is slightly less than `log10(2)`, so the estimate is the result
+                // minus one, the result or the result plus one.
+                let estimate = ((bits * 1233) >> 12) as u32;
+                let ten = Self::_from_u8(10);
+                let (power, overflow) = ten._pow(estimate);
+                if overflow || self < &power {
+                    return Some(estimate - 1);
+                }
+                let (power, overflow) = ten._pow(estimate + 1);
+                if !overflow && self >= &power {
+                    Some(estimate + 1)
+                } else {
+                    Some(estimate)
+                }
+            }
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number is zero.
+            #[inline]
+            pub fn ilog10(&self) -> u32 {
+                if let Some(ret) = self.checked_ilog10() {
+                    ret
+                } else {
+                    panic!(
+                        "{}: argument of integer logarithm must be positive",
+                        stringify!(#name)
+                    )
+                }
+            }
+        );
+        self.defun(part);
+    }
 }
@@ -32,6 +32,7 @@ impl UintConstructor {
         self.defun_priv_conv();
         self.defun_pub_conv();
         self.impl_traits_std_convert();
+        self.impl_traits_std_str();
 
         self.defun_as_prim();
         self.impl_traits_std_fmt();
@@ -40,7 +41,12 @@ impl UintConstructor {
 
         self.with_rand();
         self.with_heapsize();
+        self.with_bytemuck();
+        self.with_arbitrary();
+        self.with_proptest();
+        self.with_quickcheck();
         self.with_serde();
+        self.with_num_traits();
 
         self.output(ucs)
     }
@@ -26,6 +26,7 @@ impl ::std::convert::From<definition::Definition> for UintDefinition {
 pub struct UintAttributes {
     pub size: u64,
     pub unit_size: u64,
+    pub copy: bool,
 }
 
 impl UintAttributes {
@@ -89,6 +90,7 @@ impl ::std::default::Default for UintAttributes {
         Self {
             size: 0,
             unit_size: 64,
+            copy: false,
         }
     }
 }
@@ -101,6 +103,7 @@ impl ::std::convert::From<definition::Attributes> for UintAttributes {
             match attr.key.to_string().as_ref() {
                 "size" => parse_attr_with_check!(Int, size, attr.value, ret, check),
                 "unit_size" => parse_attr_with_check!(Int, unit_size, attr.value, ret, check),
+                "copy" => parse_attr_with_check!(Bool, copy, attr.value, ret, check),
                 key => panic!("Unknown attribute `{}`", key),
             }
         }
@@ -66,3 +66,60 @@ where
 {
     vals.map(pure_uint_to_ts).collect()
 }
+
+/// Get a helper which pads the formatted output by the width, the fill, the alignment and the
+/// sign-aware zero-padding flag of the formatter.
+///
+/// The helper writes the prefix (the sign or `0x`) then the body, the amount of characters of the
+/// body is required to compute the padding.
+pub fn fmt_padded_ts() -> TokenStream {
+    quote!(
+        #[inline]
+        pub(crate) fn fmt_padded<F>(
+            f: &mut ::core::fmt::Formatter,
+            prefix: &str,
+            body_len: usize,
+            write_body: F,
+        ) -> ::core::fmt::Result
+        where
+            F: FnOnce(&mut ::core::fmt::Formatter) -> ::core::fmt::Result,
+        {
+            use core::fmt::Write;
+            let total = prefix.len() + body_len;
+            match f.width() {
+                Some(width) if width > total => {
+                    let padding = width - total;
+                    if f.sign_aware_zero_pad() {
+                        f.write_str(prefix)?;
+                        for _ in 0..padding {
+                            f.write_char('0')?;
+                        }
+                        write_body(f)
+                    } else {
+                        let (pre, post) = match f.align() {
+                            Some(::core::fmt::Alignment::Left) => (0, padding),
+                            Some(::core::fmt::Alignment::Center) => {
+                                (padding / 2, padding - padding / 2)
+                            }
+                            _ => (padding, 0),
+                        };
+                        let fill = f.fill();
+                        for _ in 0..pre {
+                            f.write_char(fill)?;
+                        }
+                        f.write_str(prefix)?;
+                        write_body(f)?;
+                        for _ in 0..post {
+                            f.write_char(fill)?;
+                        }
+                        Ok(())
+                    }
+                }
+                _ => {
+                    f.write_str(prefix)?;
+                    write_body(f)
+                }
+            }
+        }
+    )
+}
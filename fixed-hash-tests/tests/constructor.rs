@@ -31,3 +31,19 @@ fn constructor() {
         assert_eq!(H64MAX, y);
     }
 }
+
+#[test]
+fn constants_in_patterns() {
+    let check = |x: &H128| match *x {
+        H128_EMPTY => 0,
+        H128_FULL => 2,
+        _ => 1,
+    };
+    assert_eq!(check(&H128::empty()), 0);
+    assert_eq!(check(&H128::full()), 2);
+    assert_eq!(check(&h128!("0x1")), 1);
+    match H4096::from_trimmed_hex_str("ffffffffffffffff").unwrap() {
+        H64MAX => {}
+        _ => panic!("failed to match the constant"),
+    }
+}
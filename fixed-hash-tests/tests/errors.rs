@@ -44,3 +44,14 @@ fn errors() {
         }
     }
 }
+
+#[test]
+fn display() {
+    let err = H128::from_slice(&[0u8; 15]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to convert from slice since invalid length: 15"
+    );
+    let source = std::error::Error::source(&err).unwrap();
+    assert_eq!(source.to_string(), "invalid length: 15");
+}
@@ -0,0 +1,34 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use arbitrary::{Arbitrary, Unstructured};
+
+macro_rules! check_arbitrary {
+    ($name:ident, $hash:ident, $bytes:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(nfhash::$hash::size_hint(0), ($bytes, Some($bytes)));
+            let data = [0xffu8; $bytes * 2];
+            let mut u = Unstructured::new(&data[..]);
+            let x = nfhash::$hash::arbitrary(&mut u).unwrap();
+            assert!(x.is_full());
+            assert_eq!(u.len(), $bytes);
+        }
+    };
+}
+
+check_arbitrary!(arbitrary_h128, H128, 16);
+check_arbitrary!(arbitrary_h160, H160, 20);
+check_arbitrary!(arbitrary_h224, H224, 28);
+check_arbitrary!(arbitrary_h256, H256, 32);
+check_arbitrary!(arbitrary_h384, H384, 48);
+check_arbitrary!(arbitrary_h512, H512, 64);
+check_arbitrary!(arbitrary_h520, H520, 65);
+check_arbitrary!(arbitrary_h1024, H1024, 128);
+check_arbitrary!(arbitrary_h2048, H2048, 256);
+check_arbitrary!(arbitrary_h4096, H4096, 512);
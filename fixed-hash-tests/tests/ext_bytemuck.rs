@@ -0,0 +1,37 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use zerocopy::{AsBytes, FromBytes, LayoutVerified};
+
+#[test]
+fn zeroed() {
+    let x: nfhash::H256 = bytemuck::Zeroable::zeroed();
+    assert!(x.is_empty());
+    let y = nfhash::H4096::new_zeroed();
+    assert!(y.is_empty());
+}
+
+#[test]
+fn cast_slice() {
+    let mut bytes = [0u8; 64];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let xs: &[nfhash::H256] = bytemuck::cast_slice(&bytes[..]);
+    assert_eq!(xs.len(), 2);
+    for (x, chunk) in xs.iter().zip(bytes.chunks(32)) {
+        assert_eq!(x, &nfhash::H256::from_slice(chunk).unwrap());
+    }
+    assert_eq!(bytemuck::cast_slice::<_, u8>(xs), &bytes[..]);
+    let x = LayoutVerified::<_, nfhash::H256>::new(&bytes[32..])
+        .unwrap()
+        .into_ref();
+    assert_eq!(x, &xs[1]);
+    assert_eq!(AsBytes::as_bytes(x), &bytes[32..]);
+    assert!(LayoutVerified::<_, nfhash::H256>::new(&bytes[..31]).is_none());
+}
@@ -0,0 +1,31 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use proptest::{prelude::any, proptest};
+
+macro_rules! check_proptest {
+    ($name:ident, $hash:ident) => {
+        proptest! {
+            #[test]
+            fn $name(ref x in any::<nfhash::$hash>()) {
+                assert_eq!(&nfhash::$hash::from_slice(x.as_bytes()).unwrap(), x);
+            }
+        }
+    };
+}
+
+check_proptest!(proptest_h128, H128);
+check_proptest!(proptest_h160, H160);
+check_proptest!(proptest_h224, H224);
+check_proptest!(proptest_h256, H256);
+check_proptest!(proptest_h384, H384);
+check_proptest!(proptest_h512, H512);
+check_proptest!(proptest_h520, H520);
+check_proptest!(proptest_h1024, H1024);
+check_proptest!(proptest_h2048, H2048);
+check_proptest!(proptest_h4096, H4096);
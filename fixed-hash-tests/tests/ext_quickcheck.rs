@@ -0,0 +1,66 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quickcheck::{Arbitrary, Gen};
+
+// Shrink greedily as quickcheck does, return the minimal value which still fails.
+fn minimize<T: Arbitrary>(mut val: T, fails: impl Fn(&T) -> bool) -> T {
+    while let Some(next) = val.shrink().find(|x| fails(x)) {
+        val = next;
+    }
+    val
+}
+
+fn count_ones(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|b| b.count_ones()).sum()
+}
+
+macro_rules! check_quickcheck {
+    ($mod_name:ident, $hash:ident) => {
+        mod $mod_name {
+            use super::*;
+
+            #[test]
+            fn edge_cases() {
+                let mut g = Gen::new(100);
+                let values = (0..1000)
+                    .map(|_| nfhash::$hash::arbitrary(&mut g))
+                    .collect::<Vec<_>>();
+                assert!(values.iter().any(|x| x.is_empty()));
+                assert!(values.iter().any(|x| x.is_full()));
+                assert!(values.iter().any(|x| count_ones(x.as_bytes()) == 1));
+            }
+
+            #[test]
+            fn shrink() {
+                let mut g = Gen::new(100);
+                for _ in 0..100 {
+                    let x = nfhash::$hash::arbitrary(&mut g);
+                    for y in x.shrink() {
+                        assert!(count_ones(y.as_bytes()) < count_ones(x.as_bytes()));
+                    }
+                }
+                let x = minimize(nfhash::$hash::full(), |x| x.bit(9) == Some(true));
+                let mut expected = nfhash::$hash::empty();
+                expected.set_bit(9, true);
+                assert_eq!(x, expected);
+            }
+        }
+    };
+}
+
+check_quickcheck!(quickcheck_h128, H128);
+check_quickcheck!(quickcheck_h160, H160);
+check_quickcheck!(quickcheck_h224, H224);
+check_quickcheck!(quickcheck_h256, H256);
+check_quickcheck!(quickcheck_h384, H384);
+check_quickcheck!(quickcheck_h512, H512);
+check_quickcheck!(quickcheck_h520, H520);
+check_quickcheck!(quickcheck_h1024, H1024);
+check_quickcheck!(quickcheck_h2048, H2048);
+check_quickcheck!(quickcheck_h4096, H4096);
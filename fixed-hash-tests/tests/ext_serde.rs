@@ -70,3 +70,54 @@ check_de_error!(
     "\"0x[[:xdigit:]]{65,}\"",
     "invalid length"
 );
+
+proptest! {
+    #[test]
+    fn binary_roundtrip(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let bytes = bincode::serialize(&val).unwrap();
+        // fixed-size bytes without the length prefix
+        assert_eq!(&bytes[..], val.as_bytes());
+        let result: nfhash::H256 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(val, result);
+    }
+}
+
+#[test]
+fn binary_errors() {
+    assert!(bincode::deserialize::<nfhash::H256>(&[0u8; 31]).is_err());
+    let val = nfhash::H4096::full();
+    let bytes = bincode::serialize(&val).unwrap();
+    assert_eq!(bytes.len(), 512);
+    assert_eq!(bincode::deserialize::<nfhash::H4096>(&bytes).unwrap(), val);
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Lenient(#[serde(with = "nfhash::serde::lenient")] nfhash::H128);
+
+#[test]
+fn lenient() {
+    let expected = Lenient(nfhash::H128::from_hex_str("0123456789abcdef0123456789abcdef").unwrap());
+    for json in &[
+        "\"0x0123456789abcdef0123456789abcdef\"",
+        "\"0X0123456789ABCDEF0123456789ABCDEF\"",
+        "\"0123456789abcdef0123456789ABCDEF\"",
+    ] {
+        assert_eq!(serde_json::from_str::<Lenient>(json).unwrap(), expected);
+    }
+    assert_eq!(
+        serde_json::to_string(&expected).unwrap(),
+        "\"0x0123456789abcdef0123456789abcdef\""
+    );
+    for json in &[
+        "\"0x\"",
+        "\"0123456789abcdef0123456789abcde\"",
+        "\"0x0123456789abcdef0123456789abcdef0\"",
+        "\"0x0123456789abcdef0123456789abcdeg\"",
+    ] {
+        assert!(serde_json::from_str::<Lenient>(json).is_err(), "{}", json);
+    }
+    let bytes = bincode::serialize(&expected).unwrap();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bincode::deserialize::<Lenient>(&bytes).unwrap(), expected);
+}
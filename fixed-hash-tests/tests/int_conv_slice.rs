@@ -35,4 +35,27 @@ proptest! {
         };
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn ref_from_slice(ref bytes in any::<props::H256Bytes>()) {
+        let expected = nfhash::H256::from_slice(&bytes.inner[..]).unwrap();
+        let mut inner = bytes.inner;
+        assert_eq!(nfhash::H256::from_bytes_ref(&inner), &expected);
+        assert_eq!(nfhash::H256::try_ref_from_slice(&inner[..]).unwrap(), &expected);
+        assert!(nfhash::H256::try_ref_from_slice(&inner[1..]).is_err());
+        nfhash::H256::try_mut_from_slice(&mut inner[..]).unwrap().set_byte(0, 0xff);
+        assert_eq!(inner[0], 0xff);
+        *nfhash::H256::from_bytes_mut(&mut inner) = expected;
+        assert_eq!(inner, bytes.inner);
+    }
+
+    #[test]
+    fn borrow_as_slice(ref bytes in any::<props::H256Bytes>()) {
+        let mut map = ::std::collections::HashMap::new();
+        map.insert(nfhash::H256::from(&bytes.inner), ());
+        assert!(map.contains_key(&bytes.inner[..]));
+        let mut set = ::std::collections::BTreeSet::new();
+        set.insert(nfhash::H256::from(&bytes.inner));
+        assert!(set.contains(&bytes.inner[..]));
+    }
 }
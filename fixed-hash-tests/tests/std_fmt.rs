@@ -72,6 +72,16 @@ fn debug() {
     check_fmt!(
         "{:?}",
         nfhash::H128::min_value(),
+        "0x00000000000000000000000000000000"
+    );
+    check_fmt!(
+        "{:?}",
+        nfhash::H128::max_value(),
+        "0xffffffffffffffffffffffffffffffff"
+    );
+    check_fmt!(
+        "{:?}",
+        nfhash::H128::min_value().debug_limbs(),
         "H128 ( [ \
          0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, \
          0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 \
@@ -79,12 +89,15 @@ fn debug() {
     );
     check_fmt!(
         "{:?}",
-        nfhash::H128::max_value(),
+        nfhash::H128::max_value().debug_limbs(),
         "H128 ( [ \
          0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, \
          0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff \
          ] )"
     );
+    let val = nfhash::H128::max_value();
+    assert_eq!(format!("{:#?}", val), format!("{:#?}", val.debug_limbs()));
+    assert!(format!("{:#?}", val).starts_with("H128 ( [\n    0xff,\n"));
 }
 
 #[test]
@@ -110,3 +123,54 @@ fn display() {
         "ffffffffffff..(omit 1000)..ffffffffffff"
     );
 }
+
+#[test]
+fn hex_padding() {
+    // the highest digit is not zero, so the hash is formatted as same as the primitive
+    let val = nfhash::H128::from_hex_str("f123456789abcdef0123456789abcdef").unwrap();
+    let x = 0xf123_4567_89ab_cdef_0123_4567_89ab_cdefu128;
+    check_fmt!("{:40x}", val, format!("{:40x}", x));
+    check_fmt!("{:<40X}", val, format!("{:<40X}", x));
+    check_fmt!("{:*^40x}", val, format!("{:*^40x}", x));
+    check_fmt!("{:#040x}", val, format!("{:#040x}", x));
+    check_fmt!("{:+#X}", val, format!("{:+#X}", x));
+    check_fmt!("{:10x}", val, format!("{:10x}", x));
+    let zero = nfhash::H128::empty();
+    check_fmt!(
+        "{:>#36x}",
+        zero,
+        format!("{:>36}", format!("0x{}", "0".repeat(32)))
+    );
+}
+
+#[test]
+fn short() {
+    let val = nfhash::H256::from_hex_str(
+        "1234567890abcdef00000000000000000000000000000000000000000000cdef",
+    )
+    .unwrap();
+    check_fmt!("{:.4}", val, "1234…cdef");
+    check_fmt!("{:#.4}", val, "0x1234…cdef");
+    check_fmt!("{:.6x}", val, "123456…00cdef");
+    check_fmt!("{:#.2X}", val, "0x12…EF");
+    check_fmt!("{:.0}", val, "…");
+    check_fmt!("{:>14.4}", val, "     1234…cdef");
+    check_fmt!("{:*<14.4x}", val, "1234…cdef*****");
+    check_fmt!("{:^14.4x}", val, "  1234…cdef   ");
+    check_fmt!("{:#016.4x}", val, "0x000001234…cdef");
+    check_fmt!(
+        "{:.32}",
+        val,
+        "1234567890abcdef00000000000000000000000000000000000000000000cdef"
+    );
+    check_fmt!("{}", val.short(), "0x1234…cdef");
+    check_fmt!("{:?}", val.short(), "0x1234…cdef");
+    check_fmt!("{:.3}", val.short(), "0x123…def");
+    check_fmt!("{:>14}", val.short(), "   0x1234…cdef");
+    check_fmt!("{:-<14.3}", val.short(), "0x123…def-----");
+    check_fmt!("{:^16?}", val.short(), "  0x1234…cdef   ");
+    check_fmt!("{:014}", val.short(), "0x0001234…cdef");
+    check_fmt!("{:8}", val.short(), "0x1234…cdef");
+    let short: nfhash::ShortHash<nfhash::H256> = val.short();
+    assert_eq!(short.to_string(), "0x1234…cdef");
+}
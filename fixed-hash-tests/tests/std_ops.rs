@@ -0,0 +1,43 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate proptest;
+
+use nfhash_tests::props;
+use proptest::prelude::any;
+
+macro_rules! std_ops_bitwise {
+    ($opr:tt, $opr_assign:tt, $name:ident) => {
+        proptest! {
+            #[test]
+            fn $name(ref lhs in any::<props::H256Bytes>(), ref rhs in any::<props::H256Bytes>()) {
+                let lhs: nfhash::H256 = lhs.into();
+                let rhs: nfhash::H256 = rhs.into();
+                let mut expected = [0u8; 32];
+                for (idx, byte) in expected.iter_mut().enumerate() {
+                    *byte = lhs.as_bytes()[idx] $opr rhs.as_bytes()[idx];
+                }
+                let expected: nfhash::H256 = expected.into();
+                assert_eq!(&lhs $opr &rhs, expected);
+                assert_eq!(lhs $opr &rhs, expected);
+                assert_eq!(lhs $opr rhs, expected);
+                let mut x = lhs;
+                x $opr_assign rhs;
+                assert_eq!(x, expected);
+                let mut x = lhs;
+                x $opr_assign &rhs;
+                assert_eq!(x, expected);
+            }
+        }
+    };
+}
+
+std_ops_bitwise!(&, &=, bitand);
+std_ops_bitwise!(|, |=, bitor);
+std_ops_bitwise!(^, ^=, bitxor);
@@ -14,8 +14,16 @@
 //!
 //! [numext-fixed-hash]: https://docs.rs/numext-fixed-hash
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 extern crate constructor;
 
+#[cfg(feature = "std")]
 use thiserror::Error;
 
 #[macro_use]
@@ -24,21 +32,27 @@ mod tools;
 constructor::construct_fixed_hashes!(
     H128 {
         size = 128,
+        copy = true,
     },
     H160 {
         size = 160,
+        copy = true,
     },
     H224 {
         size = 224,
+        copy = true,
     },
     H256 {
         size = 256,
+        copy = true,
     },
     H384 {
         size = 384,
+        copy = true,
     },
     H512 {
         size = 512,
+        copy = true,
     },
     H520 {
         size = 520,
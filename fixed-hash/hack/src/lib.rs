@@ -47,7 +47,7 @@ macro_rules! impl_func {
                 .unwrap_or_else(|err| {
                     panic!("Failed to parse the input hexadecimal string: {}", err);
                 });
-                let eval_str = format!("{:?}", value);
+                let eval_str = format!("{:?}", value.debug_limbs());
                 let eval_ts: proc_macro2::TokenStream = eval_str.parse().unwrap_or_else(|_| {
                     panic!("Failed to parse the string \"{}\" to TokenStream.", eval_str);
                 });
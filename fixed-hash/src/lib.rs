@@ -34,12 +34,32 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # `no_std`
+//!
+//! This crate is `no_std` when the default feature `std` is disabled.
+//!
+//! - The feature `alloc` enables the APIs which return allocated values, such as `to_vec`.
+//! - The feature `std` enables `alloc`, and implements `std::error::Error` for the errors.
+//! - The features `support_rand`, `support_heapsize`, `support_serde`, `support_arbitrary`,
+//!   `support_proptest` and `support_quickcheck` require `std`.
+//!
+//! # Byte casting
+//!
+//! The feature `support_bytemuck` implements `bytemuck::Zeroable` and
+//! `bytemuck::TransparentWrapper` for all fixed hashes, and `bytemuck::Pod` for the fixed hashes
+//! which implement `Copy`.
+//! `H1024`, `H2048` and `H4096` are not `Copy`, so they are not `Pod`.
+
+#![no_std]
 
 extern crate nfhash_core;
 extern crate nfhash_hack;
 
 pub use nfhash_core::prelude;
-pub use nfhash_core::{FixedHashError, FromSliceError, FromStrError, IntoSliceError};
+#[cfg(feature = "support_serde")]
+pub use nfhash_core::serde_helpers as serde;
+pub use nfhash_core::{FixedHashError, FromSliceError, FromStrError, IntoSliceError, ShortHash};
 
 macro_rules! reexport {
     ($name:ident, $macro_name:ident) => {
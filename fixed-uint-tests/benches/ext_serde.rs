@@ -8,36 +8,59 @@
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+macro_rules! serde_bench {
+    ($c:ident, $suffix:expr, $nfval:expr, $etval:expr) => {{
+        let x = $nfval;
+        let y = $etval;
+
+        let x_json = serde_json::to_string(&x).unwrap();
+        let y_json = serde_json::to_string(&y).unwrap();
+
+        $c.bench_function(concat!("serde/ser/nfuint/", $suffix), move |b| {
+            b.iter(|| {
+                let _ = serde_json::to_string(&x);
+            })
+        });
+
+        $c.bench_function(concat!("serde/ser/etypes/", $suffix), move |b| {
+            b.iter(|| {
+                let _ = serde_json::to_string(&y);
+            })
+        });
+
+        $c.bench_function(concat!("serde/de/nfuint/", $suffix), move |b| {
+            b.iter(|| {
+                let _: nfuint::U256 = serde_json::from_str(&x_json).unwrap();
+            })
+        });
+
+        $c.bench_function(concat!("serde/de/etypes/", $suffix), move |b| {
+            b.iter(|| {
+                let _: etypes::U256 = serde_json::from_str(&y_json).unwrap();
+            })
+        });
+    }};
+}
+
 fn serde(c: &mut Criterion) {
-    let x = nfuint::U256::max_value();
-    let y = etypes::U256::max_value();
-
-    let x_json = serde_json::to_string(&x).unwrap();
-    let y_json = serde_json::to_string(&y).unwrap();
-
-    c.bench_function("serde/ser/nfuint/u256", move |b| {
-        b.iter(|| {
-            let _ = serde_json::to_string(&x);
-        })
-    });
-
-    c.bench_function("serde/ser/etypes/u256", move |b| {
-        b.iter(|| {
-            let _ = serde_json::to_string(&y);
-        })
-    });
-
-    c.bench_function("serde/de/nfuint/u256", move |b| {
-        b.iter(|| {
-            let _: nfuint::U256 = serde_json::from_str(&x_json).unwrap();
-        })
-    });
-
-    c.bench_function("serde/de/etypes/u256", move |b| {
-        b.iter(|| {
-            let _: etypes::U256 = serde_json::from_str(&y_json).unwrap();
-        })
-    });
+    serde_bench!(
+        c,
+        "u256",
+        nfuint::U256::max_value(),
+        etypes::U256::max_value()
+    );
+    serde_bench!(
+        c,
+        "u256/half",
+        nfuint::U256::max_value() >> 128,
+        etypes::U256::max_value() >> 128
+    );
+    serde_bench!(
+        c,
+        "u256/small",
+        nfuint::U256::from(0x1234u16),
+        etypes::U256::from(0x1234u16)
+    );
 }
 
 criterion_group!(ext, serde);
@@ -0,0 +1,65 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn dec_str(c: &mut Criterion) {
+    let x = nfuint::U256::max_value();
+    let y = etypes::U256::max_value();
+    let z = num_bigint::BigUint::parse_bytes(x.to_string().as_bytes(), 10).unwrap();
+    let dec = x.to_string();
+
+    c.bench_function("dec_str/fmt/nfuint/u256", move |b| {
+        b.iter(|| black_box(&x).to_string())
+    });
+
+    c.bench_function("dec_str/fmt/etypes/u256", move |b| {
+        b.iter(|| black_box(&y).to_string())
+    });
+
+    c.bench_function("dec_str/fmt/num_bigint/u256", move |b| {
+        b.iter(|| black_box(&z).to_string())
+    });
+
+    c.bench_function("dec_str/write/nfuint/u256", move |b| {
+        let mut buf = [0u8; 78];
+        b.iter(|| {
+            let _ = black_box(&x).write_dec_to(&mut buf);
+        })
+    });
+
+    let dec_clone = dec.clone();
+    c.bench_function("dec_str/parse/nfuint/u256", move |b| {
+        b.iter(|| nfuint::U256::from_dec_str(black_box(&dec_clone)).unwrap())
+    });
+
+    let dec_clone = dec.clone();
+    c.bench_function("dec_str/parse/etypes/u256", move |b| {
+        b.iter(|| etypes::U256::from_dec_str(black_box(&dec_clone)).unwrap())
+    });
+
+    c.bench_function("dec_str/parse/num_bigint/u256", move |b| {
+        b.iter(|| num_bigint::BigUint::parse_bytes(black_box(dec.as_bytes()), 10).unwrap())
+    });
+}
+
+fn oct_str(c: &mut Criterion) {
+    let x = nfuint::U256::max_value();
+    let z = num_bigint::BigUint::parse_bytes(x.to_string().as_bytes(), 10).unwrap();
+
+    c.bench_function("oct_str/fmt/nfuint/u256", move |b| {
+        b.iter(|| format!("{:o}", black_box(&x)))
+    });
+
+    c.bench_function("oct_str/fmt/num_bigint/u256", move |b| {
+        b.iter(|| format!("{:o}", black_box(&z)))
+    });
+}
+
+criterion_group!(conversion, dec_str, oct_str);
+criterion_main!(conversion);
@@ -0,0 +1,67 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fixed uints with custom unit sizes.
+//!
+//! The presets only use the unit size 64 unless the size requires a smaller one.
+//! The name suffix is the unit size, for example, `U256U8` is a 256-bits uint with 8-bits units.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+constructor::construct_fixed_uints!(
+    U128U8 {
+        size = 128,
+        unit_size = 8,
+        copy = true,
+    },
+    U256U8 {
+        size = 256,
+        unit_size = 8,
+        copy = true,
+    },
+    U520U8 {
+        size = 520,
+        unit_size = 8,
+    },
+    U128U16 {
+        size = 128,
+        unit_size = 16,
+        copy = true,
+    },
+    U272U16 {
+        size = 272,
+        unit_size = 16,
+        copy = true,
+    },
+    U1024U16 {
+        size = 1024,
+        unit_size = 16,
+    },
+    U160U32 {
+        size = 160,
+        unit_size = 32,
+        copy = true,
+    },
+    U256U32 {
+        size = 256,
+        unit_size = 32,
+        copy = true,
+    },
+    U1024U32 {
+        size = 1024,
+        unit_size = 32,
+    },
+);
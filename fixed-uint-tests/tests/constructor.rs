@@ -100,3 +100,21 @@ fn constructor() {
         assert_eq!(x, y);
     }
 }
+
+const U4096_ONE: U4096 = u4096!("1");
+
+#[test]
+fn constants_in_patterns() {
+    let check = |x: &U128| match *x {
+        U128_ZERO => 0,
+        U128_100 => 100,
+        _ => 1,
+    };
+    assert_eq!(check(&U128::zero()), 0);
+    assert_eq!(check(&U128::from(100u8)), 100);
+    assert_eq!(check(&U128::from(99u8)), 1);
+    match U4096::one() {
+        U4096_ONE => {}
+        _ => panic!("failed to match the constant"),
+    }
+}
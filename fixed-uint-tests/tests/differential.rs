@@ -0,0 +1,264 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Differential tests against `num-bigint` modulo `2^bits`, for every preset size and some
+//! custom unit sizes.
+
+use num_bigint::BigUint;
+use num_traits::{
+    One, WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr, WrappingSub, Zero,
+};
+use proptest::prelude::{any, prop_oneof, Just, Strategy};
+use proptest::{collection, proptest};
+
+fn modulus(bits: usize) -> BigUint {
+    BigUint::one() << bits
+}
+
+// The scientific notation with all significant digits, the same as the primitives.
+fn sci_str(val: &BigUint) -> String {
+    let digits = val.to_str_radix(10);
+    let exp = digits.len() - 1;
+    let trimmed = digits.trim_end_matches('0');
+    if trimmed.len() <= 1 {
+        format!("{}e{}", &digits[..1], exp)
+    } else {
+        format!("{}.{}e{}", &trimmed[..1], &trimmed[1..], exp)
+    }
+}
+
+// Little-endian bytes, mixed with zero, max, powers of two and short values.
+fn bytes(size: usize) -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        4 => collection::vec(any::<u8>(), size),
+        1 => collection::vec(any::<u8>(), 0..=size / 2),
+        1 => Just(vec![0u8; size]),
+        1 => Just(vec![!0u8; size]),
+        1 => (0..size * 8).prop_map(move |idx| {
+            let mut ret = vec![0u8; size];
+            ret[idx / 8] = 1 << (idx % 8);
+            ret
+        }),
+    ]
+}
+
+macro_rules! differential {
+    ($mod_name:ident, $uint:ty, $bits:expr) => {
+        mod $mod_name {
+            use super::*;
+
+            type Uint = $uint;
+            const BITS: usize = $bits;
+            const BYTES: usize = $bits / 8;
+
+            fn uint() -> impl Strategy<Value = Uint> {
+                bytes(BYTES).prop_map(|bytes| Uint::from_little_endian(&bytes).unwrap())
+            }
+
+            fn to_big(val: &Uint) -> BigUint {
+                let mut bytes = vec![0u8; BYTES];
+                val.into_little_endian(&mut bytes).unwrap();
+                BigUint::from_bytes_le(&bytes)
+            }
+
+            fn from_big(val: &BigUint) -> Uint {
+                let mut bytes = (val % modulus(BITS)).to_bytes_le();
+                bytes.resize(BYTES, 0);
+                Uint::from_little_endian(&bytes).unwrap()
+            }
+
+            fn checked(val: &BigUint) -> Option<Uint> {
+                if val < &modulus(BITS) {
+                    Some(from_big(val))
+                } else {
+                    None
+                }
+            }
+
+            fn saturated(val: &BigUint) -> Uint {
+                checked(val).unwrap_or_else(Uint::max_value)
+            }
+
+            proptest! {
+                #[test]
+                fn arith(ref lhs in uint(), ref rhs in uint()) {
+                    let m = modulus(BITS);
+                    let (a, b) = (to_big(lhs), to_big(rhs));
+                    let sum = &a + &b;
+                    assert_eq!(lhs.overflowing_add(rhs), (from_big(&sum), sum >= m));
+                    assert_eq!(lhs.checked_add(rhs), checked(&sum));
+                    assert_eq!(lhs.saturating_add(rhs), saturated(&sum));
+                    let diff = &a + &m - &b;
+                    assert_eq!(lhs.overflowing_sub(rhs), (from_big(&diff), a < b));
+                    if a >= b {
+                        assert_eq!(lhs.checked_sub(rhs), Some(from_big(&diff)));
+                        assert_eq!(lhs.saturating_sub(rhs), from_big(&diff));
+                    } else {
+                        assert_eq!(lhs.checked_sub(rhs), None);
+                        assert_eq!(lhs.saturating_sub(rhs), Uint::zero());
+                    }
+                    let product = &a * &b;
+                    assert_eq!(lhs.overflowing_mul(rhs), (from_big(&product), product >= m));
+                    assert_eq!(lhs.checked_mul(rhs), checked(&product));
+                    assert_eq!(lhs.saturating_mul(rhs), saturated(&product));
+                    if rhs.is_zero() {
+                        assert_eq!(lhs.checked_div(rhs), None);
+                        assert_eq!(lhs.checked_rem(rhs), None);
+                    } else {
+                        assert_eq!(lhs / rhs, from_big(&(&a / &b)));
+                        assert_eq!(lhs % rhs, from_big(&(&a % &b)));
+                        assert_eq!(lhs.checked_div(rhs), Some(from_big(&(&a / &b))));
+                        assert_eq!(lhs.checked_rem(rhs), Some(from_big(&(&a % &b))));
+                    }
+                    assert_eq!(lhs & rhs, from_big(&(&a & &b)));
+                    assert_eq!(lhs | rhs, from_big(&(&a | &b)));
+                    assert_eq!(lhs ^ rhs, from_big(&(&a ^ &b)));
+                    assert_eq!(!lhs, from_big(&(&m - 1u8 - &a)));
+                    assert_eq!(lhs.overflowing_neg(), (from_big(&(&m - &a)), !lhs.is_zero()));
+                    assert_eq!(lhs.cmp(rhs), a.cmp(&b));
+                }
+
+                #[test]
+                fn wrapping(ref lhs in uint(), ref rhs in uint(), shift in 0..(BITS as u32 * 2)) {
+                    let m = modulus(BITS);
+                    let (a, b) = (to_big(lhs), to_big(rhs));
+                    assert_eq!(lhs.wrapping_add(rhs), from_big(&(&a + &b)));
+                    assert_eq!(lhs.wrapping_sub(rhs), from_big(&(&a + &m - &b)));
+                    assert_eq!(lhs.wrapping_mul(rhs), from_big(&(&a * &b)));
+                    assert_eq!(lhs.wrapping_neg(), from_big(&(&m - &a)));
+                    let masked = shift as usize % BITS;
+                    assert_eq!(lhs.wrapping_shl(shift), from_big(&(&a << masked)));
+                    assert_eq!(lhs.wrapping_shr(shift), from_big(&(&a >> masked)));
+                }
+
+                #[test]
+                fn shift(ref val in uint(), rhs in 0..(BITS as u128 * 2)) {
+                    let a = to_big(val);
+                    let masked = (rhs % BITS as u128) as usize;
+                    let overflow = rhs >= BITS as u128;
+                    let (shl, shr) = (from_big(&(&a << masked)), from_big(&(&a >> masked)));
+                    assert_eq!(val.overflowing_shl(rhs), (shl.clone(), overflow));
+                    assert_eq!(val.overflowing_shr(rhs), (shr.clone(), overflow));
+                    assert_eq!(val.checked_shl(rhs), Some(shl).filter(|_| !overflow));
+                    assert_eq!(val.checked_shr(rhs), Some(shr).filter(|_| !overflow));
+                    assert_eq!(val << rhs, from_big(&(&a << rhs as usize)));
+                    assert_eq!(val >> rhs, from_big(&(&a >> rhs as usize)));
+                }
+
+                #[test]
+                fn pow(ref val in uint(), exp in 0u32..40) {
+                    let expected = num_traits::pow(to_big(val), exp as usize);
+                    let overflow = expected >= modulus(BITS);
+                    assert_eq!(val.overflowing_pow(exp), (from_big(&expected), overflow));
+                    assert_eq!(val.checked_pow(exp), checked(&expected));
+                    assert_eq!(val.saturating_pow(exp), saturated(&expected));
+                }
+
+                #[test]
+                fn gcd(ref lhs in uint(), ref rhs in uint()) {
+                    let expected = num_integer::Integer::gcd(&to_big(lhs), &to_big(rhs));
+                    assert_eq!(lhs.gcd(rhs), from_big(&expected));
+                }
+
+                #[test]
+                fn string(ref val in uint(), radix in 2u32..=36) {
+                    let a = to_big(val);
+                    let expected = a.to_str_radix(radix);
+                    assert_eq!(val.to_str_radix(radix), expected);
+                    assert_eq!(&Uint::from_str_radix(&expected, radix).unwrap(), val);
+                    assert_eq!(val.to_string(), a.to_str_radix(10));
+                    assert_eq!(format!("{:b}", val), a.to_str_radix(2));
+                    assert_eq!(format!("{:o}", val), a.to_str_radix(8));
+                    assert_eq!(format!("{:x}", val), a.to_str_radix(16));
+                    assert_eq!(format!("{:X}", val), a.to_str_radix(16).to_uppercase());
+                    assert_eq!(&Uint::from_dec_str(&a.to_str_radix(10)).unwrap(), val);
+                    assert_eq!(&Uint::from_hex_str(&a.to_str_radix(16)).unwrap(), val);
+                }
+
+                #[test]
+                fn sci(ref val in uint()) {
+                    let a = to_big(val);
+                    let expected = sci_str(&a);
+                    assert_eq!(format!("{:e}", val), expected);
+                    assert_eq!(format!("{:E}", val), expected.to_uppercase());
+                    assert_eq!(&Uint::from_sci_str(&expected).unwrap(), val);
+                    let expected = if a.is_zero() {
+                        None
+                    } else {
+                        Some(a.to_str_radix(10).len() as u32 - 1)
+                    };
+                    assert_eq!(val.checked_ilog10(), expected);
+                }
+
+                #[test]
+                fn slice(ref input in collection::vec(any::<u8>(), 0..=BYTES + 1)) {
+                    let le = Uint::from_little_endian(input);
+                    let be = Uint::from_big_endian(input);
+                    if input.len() > BYTES {
+                        assert!(le.is_err() && be.is_err());
+                    } else {
+                        let le = le.unwrap();
+                        let be = be.unwrap();
+                        assert_eq!(le, from_big(&BigUint::from_bytes_le(input)));
+                        assert_eq!(be, from_big(&BigUint::from_bytes_be(input)));
+                        let mut expected = BigUint::from_bytes_le(input).to_bytes_le();
+                        expected.resize(BYTES, 0);
+                        let mut output = vec![0u8; BYTES];
+                        le.into_little_endian(&mut output).unwrap();
+                        assert_eq!(output, expected);
+                        assert_eq!(&le.to_le_bytes()[..], &expected[..]);
+                        expected.reverse();
+                        le.into_big_endian(&mut output).unwrap();
+                        assert_eq!(output, expected);
+                        assert_eq!(&le.to_be_bytes()[..], &expected[..]);
+                    }
+                }
+
+                #[test]
+                fn bits(ref val in uint()) {
+                    let a = to_big(val);
+                    let ones: u32 = a.to_bytes_le().iter().map(|x| x.count_ones()).sum();
+                    assert_eq!(val.count_ones(), ones);
+                    assert_eq!(val.leading_zeros() as usize, BITS - a.bits());
+                    let is_power_of_two = !a.is_zero() && (&a & (&a - 1u8)).is_zero();
+                    assert_eq!(val.is_power_of_two(), is_power_of_two);
+                    let next = if a <= BigUint::one() {
+                        BigUint::one()
+                    } else {
+                        BigUint::one() << (&a - 1u8).bits()
+                    };
+                    assert_eq!(val.checked_next_power_of_two(), checked(&next));
+                    if next < modulus(BITS) {
+                        assert_eq!(val.next_power_of_two(), from_big(&next));
+                    }
+                }
+            }
+        }
+    };
+}
+
+differential!(u128, nfuint::U128, 128);
+differential!(u160, nfuint::U160, 160);
+differential!(u224, nfuint::U224, 224);
+differential!(u256, nfuint::U256, 256);
+differential!(u384, nfuint::U384, 384);
+differential!(u512, nfuint::U512, 512);
+differential!(u520, nfuint::U520, 520);
+differential!(u1024, nfuint::U1024, 1024);
+differential!(u2048, nfuint::U2048, 2048);
+differential!(u4096, nfuint::U4096, 4096);
+
+differential!(u128_u8, nfuint_custom::U128U8, 128);
+differential!(u256_u8, nfuint_custom::U256U8, 256);
+differential!(u520_u8, nfuint_custom::U520U8, 520);
+differential!(u128_u16, nfuint_custom::U128U16, 128);
+differential!(u272_u16, nfuint_custom::U272U16, 272);
+differential!(u1024_u16, nfuint_custom::U1024U16, 1024);
+differential!(u160_u32, nfuint_custom::U160U32, 160);
+differential!(u256_u32, nfuint_custom::U256U32, 256);
+differential!(u1024_u32, nfuint_custom::U1024U32, 1024);
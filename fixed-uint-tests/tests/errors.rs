@@ -52,3 +52,19 @@ fn errors() {
         }
     }
 }
+
+#[test]
+fn display() {
+    let err = U128::from_hex_str("").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to parse from string since invalid length: 0"
+    );
+    let source = std::error::Error::source(&err).unwrap();
+    assert_eq!(source.to_string(), "invalid length: 0");
+    let err = U128::from_hex_str("z").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to parse from string since invalid character code `122` at 0"
+    );
+}
@@ -0,0 +1,37 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use arbitrary::{Arbitrary, Unstructured};
+
+macro_rules! check_arbitrary {
+    ($name:ident, $uint:ident, $bytes:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(nfuint::$uint::size_hint(0), ($bytes, Some($bytes)));
+            let data = [0xffu8; $bytes * 2];
+            let mut u = Unstructured::new(&data[..]);
+            let x = nfuint::$uint::arbitrary(&mut u).unwrap();
+            assert!(x.is_max());
+            assert_eq!(u.len(), $bytes);
+            let mut u = Unstructured::new(&data[..$bytes - 1]);
+            let x = nfuint::$uint::arbitrary(&mut u).unwrap();
+            assert!(x < nfuint::$uint::max_value());
+        }
+    };
+}
+
+check_arbitrary!(arbitrary_u128, U128, 16);
+check_arbitrary!(arbitrary_u160, U160, 20);
+check_arbitrary!(arbitrary_u224, U224, 28);
+check_arbitrary!(arbitrary_u256, U256, 32);
+check_arbitrary!(arbitrary_u384, U384, 48);
+check_arbitrary!(arbitrary_u512, U512, 64);
+check_arbitrary!(arbitrary_u520, U520, 65);
+check_arbitrary!(arbitrary_u1024, U1024, 128);
+check_arbitrary!(arbitrary_u2048, U2048, 256);
+check_arbitrary!(arbitrary_u4096, U4096, 512);
@@ -0,0 +1,43 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use zerocopy::{AsBytes, FromBytes, LayoutVerified};
+
+#[test]
+fn zeroed() {
+    let x: nfuint::U256 = bytemuck::Zeroable::zeroed();
+    assert!(x.is_zero());
+    let y = nfuint::U4096::new_zeroed();
+    assert!(y.is_zero());
+}
+
+#[test]
+fn transparent_wrapper() {
+    let inner = [1u64, 2, 3, 4];
+    let x: &nfuint::U256 = bytemuck::TransparentWrapper::wrap_ref(&inner);
+    assert_eq!(bytemuck::TransparentWrapper::peel(*x), inner);
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn cast_slice() {
+    let mut bytes = [0u8; 64];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let xs: &[nfuint::U256] = bytemuck::cast_slice(&bytes[..]);
+    assert_eq!(xs.len(), 2);
+    for (x, chunk) in xs.iter().zip(bytes.chunks(32)) {
+        assert_eq!(x, &nfuint::U256::from_little_endian(chunk).unwrap());
+    }
+    assert_eq!(bytemuck::cast_slice::<_, u8>(xs), &bytes[..]);
+    let x = LayoutVerified::<_, nfuint::U256>::new(&bytes[..32]).unwrap();
+    assert_eq!(*x, xs[0]);
+    assert_eq!(x.as_bytes(), &bytes[..32]);
+    assert!(LayoutVerified::<_, nfuint::U256>::new(&bytes[..31]).is_none());
+}
@@ -0,0 +1,114 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::U256;
+use nfuint_tests::props;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedSub, FromPrimitive, Num, One, Pow, SaturatingAdd, ToPrimitive,
+    WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+use proptest::{
+    prelude::{any, any_with},
+    proptest,
+};
+
+fn generic_sum<T: Zero + One + CheckedAdd>(count: usize) -> Option<T> {
+    let mut ret = T::zero();
+    for _ in 0..count {
+        ret = ret.checked_add(&T::one())?;
+    }
+    Some(ret)
+}
+
+#[test]
+fn identities() {
+    assert_eq!(generic_sum::<U256>(10), Some(U256::from(10u8)));
+    assert!(<U256 as Zero>::is_zero(&U256::zero()));
+    assert_eq!(<U256 as Bounded>::max_value(), U256::max_value());
+    assert_eq!(<U256 as Bounded>::min_value(), U256::zero());
+}
+
+#[test]
+fn from_str_radix() {
+    let x = U256::from_str_radix("zz", 36).unwrap();
+    assert_eq!(x, U256::from(36u32 * 36 - 1));
+    let x = U256::from_str_radix("120", 3).unwrap();
+    assert_eq!(x, U256::from(15u8));
+    assert!(U256::from_str_radix("3", 3).is_err());
+    assert!(U256::from_str_radix("012", 3).is_err());
+    assert!(U256::from_str_radix("", 3).is_err());
+}
+
+#[test]
+fn primitive() {
+    let max = U256::max_value();
+    assert_eq!(max.to_u64(), None);
+    assert_eq!(max.to_u128(), None);
+    let x = U256::from_u128(u128::MAX).unwrap();
+    assert_eq!(x.to_u128(), Some(u128::MAX));
+    assert_eq!(x.to_u64(), None);
+    assert_eq!(x.to_i128(), None);
+    let x = U256::from_i64(i64::MAX).unwrap();
+    assert_eq!(x.to_i64(), Some(i64::MAX));
+    assert_eq!(x.to_u32(), None);
+    assert!(U256::from_i64(-1).is_none());
+}
+
+#[test]
+fn wrapping() {
+    let max = U256::max_value();
+    let one = U256::one();
+    assert_eq!(max.wrapping_add(&one), U256::zero());
+    assert_eq!(U256::zero().wrapping_sub(&one), max);
+    assert_eq!(max.wrapping_mul(&max), one);
+    assert_eq!(SaturatingAdd::saturating_add(&max, &one), max);
+    assert_eq!(CheckedSub::checked_sub(&U256::zero(), &one), None);
+}
+
+#[test]
+fn integer() {
+    let x = U256::from(12u8);
+    let y = U256::from(18u8);
+    assert_eq!(Integer::lcm(&x, &y), U256::from(36u8));
+    assert_eq!(Integer::gcd(&x, &y), U256::from(6u8));
+    assert_eq!(Integer::lcm(&U256::zero(), &U256::zero()), U256::zero());
+    assert!(x.is_even());
+    assert!(!U256::one().is_even());
+    assert!(y.is_multiple_of(&U256::from(9u8)));
+    assert_eq!(y.div_rem(&x), (U256::one(), U256::from(6u8)));
+}
+
+#[test]
+fn pow() {
+    let x = U256::from(3u8);
+    assert_eq!(Pow::pow(&x, 4u32), U256::from(81u8));
+    assert_eq!(Pow::pow(x, 0u32), U256::one());
+}
+
+proptest! {
+    #[test]
+    fn to_str_radix_roundtrip(ref le in any::<props::U256LeBytes>(), radix in 2u32..=36) {
+        let expected: U256 = le.into();
+        let big: BigUint = le.into();
+        let result = <U256 as Num>::from_str_radix(&big.to_str_radix(radix), radix).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn lcm(ref pair in any_with::<props::U256Pair>(props::U256PairParameters::Random)) {
+        let (ref lhs, ref rhs): (BigUint, BigUint) = pair.into();
+        let y = lhs.lcm(rhs);
+        if y.bits() <= 256 {
+            let (ref lhs, ref rhs): (U256, U256) = pair.into();
+            let x = Integer::lcm(lhs, rhs);
+            assert_eq!(y.to_str_radix(16), format!("{:x}", x));
+        }
+    }
+}
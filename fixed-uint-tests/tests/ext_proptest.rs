@@ -0,0 +1,93 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::PairParameters;
+use proptest::{prelude::any, proptest};
+
+macro_rules! check_proptest {
+    ($mod_name:ident, $uint:ident) => {
+        mod $mod_name {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn any_value(ref x in any::<nfuint::$uint>()) {
+                    let bytes = x.to_le_bytes();
+                    assert_eq!(&nfuint::$uint::from_le_bytes(&bytes), x);
+                }
+
+                #[test]
+                fn between(ref x in nfuint::$uint::proptest_between(3u8.into(), 10u8.into())) {
+                    assert!(x >= &nfuint::$uint::from(3u8) && x <= &nfuint::$uint::from(10u8));
+                }
+
+                #[test]
+                fn random(ref pair in nfuint::$uint::pair_strategy(PairParameters::Random)) {
+                    assert_eq!(pair.0.checked_sub(&pair.1).is_some(), pair.0 >= pair.1);
+                }
+
+                #[test]
+                fn can_add(ref pair in nfuint::$uint::pair_strategy(PairParameters::CanAdd)) {
+                    assert!(pair.0.checked_add(&pair.1).is_some());
+                }
+
+                #[test]
+                fn can_sub(ref pair in nfuint::$uint::pair_strategy(PairParameters::CanSub)) {
+                    assert!(pair.0.checked_sub(&pair.1).is_some());
+                }
+
+                #[test]
+                fn can_mul(ref pair in nfuint::$uint::pair_strategy(PairParameters::CanMul)) {
+                    assert!(pair.0.checked_mul(&pair.1).is_some());
+                }
+
+                #[test]
+                fn can_div(ref pair in nfuint::$uint::pair_strategy(PairParameters::CanDiv)) {
+                    assert!(!pair.1.is_zero());
+                    assert!(pair.0.is_zero() || pair.1 <= pair.0);
+                }
+
+                #[test]
+                fn can_rem(ref pair in nfuint::$uint::pair_strategy(PairParameters::CanRem)) {
+                    assert!(pair.0.checked_rem(&pair.1).is_some());
+                    if pair.0.is_zero() {
+                        assert_eq!(pair.1, nfuint::$uint::one());
+                    } else {
+                        assert!(pair.1 <= pair.0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+check_proptest!(proptest_u128, U128);
+check_proptest!(proptest_u160, U160);
+check_proptest!(proptest_u224, U224);
+check_proptest!(proptest_u256, U256);
+check_proptest!(proptest_u384, U384);
+check_proptest!(proptest_u512, U512);
+check_proptest!(proptest_u520, U520);
+check_proptest!(proptest_u1024, U1024);
+check_proptest!(proptest_u2048, U2048);
+check_proptest!(proptest_u4096, U4096);
+
+#[test]
+fn can_mul_with_large_rhs() {
+    use proptest::{
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+    let mut runner = TestRunner::deterministic();
+    let strategy = nfuint::U256::pair_strategy(PairParameters::CanMul);
+    let large = nfuint::U256::from(u64::MAX);
+    let count = (0..256)
+        .filter(|_| strategy.new_tree(&mut runner).unwrap().current().1 > large)
+        .count();
+    assert!(count > 0);
+}
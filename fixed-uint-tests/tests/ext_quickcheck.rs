@@ -0,0 +1,67 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quickcheck::{Arbitrary, Gen};
+
+// Shrink greedily as quickcheck does, return the minimal value which still fails.
+fn minimize<T: Arbitrary>(mut val: T, fails: impl Fn(&T) -> bool) -> T {
+    while let Some(next) = val.shrink().find(|x| fails(x)) {
+        val = next;
+    }
+    val
+}
+
+macro_rules! check_quickcheck {
+    ($mod_name:ident, $uint:ident) => {
+        mod $mod_name {
+            use super::*;
+
+            #[test]
+            fn edge_cases() {
+                let mut g = Gen::new(100);
+                let values = (0..1000)
+                    .map(|_| nfuint::$uint::arbitrary(&mut g))
+                    .collect::<Vec<_>>();
+                assert!(values.iter().any(|x| x.is_zero()));
+                assert!(values.iter().any(|x| x == &nfuint::$uint::one()));
+                assert!(values.iter().any(|x| x.is_max()));
+                assert!(values
+                    .iter()
+                    .any(|x| x.count_ones() == 1 && x.highest_one() > Some(64)));
+            }
+
+            #[test]
+            fn shrink() {
+                let mut g = Gen::new(100);
+                for _ in 0..100 {
+                    let x = nfuint::$uint::arbitrary(&mut g);
+                    for y in x.shrink() {
+                        assert!(y < x);
+                        assert!(y.count_ones() <= x.count_ones());
+                    }
+                }
+                let min = nfuint::$uint::one() << 70;
+                let x = minimize(nfuint::$uint::max_value(), |x| x >= &min);
+                assert_eq!(x, min);
+                let x = minimize(nfuint::$uint::max_value(), |x| x.bit(0) == Some(true));
+                assert_eq!(x, nfuint::$uint::one());
+            }
+        }
+    };
+}
+
+check_quickcheck!(quickcheck_u128, U128);
+check_quickcheck!(quickcheck_u160, U160);
+check_quickcheck!(quickcheck_u224, U224);
+check_quickcheck!(quickcheck_u256, U256);
+check_quickcheck!(quickcheck_u384, U384);
+check_quickcheck!(quickcheck_u512, U512);
+check_quickcheck!(quickcheck_u520, U520);
+check_quickcheck!(quickcheck_u1024, U1024);
+check_quickcheck!(quickcheck_u2048, U2048);
+check_quickcheck!(quickcheck_u4096, U4096);
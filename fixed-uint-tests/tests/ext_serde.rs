@@ -16,11 +16,24 @@ proptest! {
         let json = serde_json::to_string(&val);
         assert!(json.is_ok());
         let json = json.unwrap();
-        let y = serde_json::from_str(&json);
+        let y = serde_json::from_str::<nfuint::U256>(&json);
         assert!(y.is_ok());
         assert_eq!(val, y.unwrap());
     }
 
+    #[test]
+    fn serialize_minimal_hex(ref le in any::<props::U256LeBytes>(), shift in 0u32..=256) {
+        let val: nfuint::U256 = le.into();
+        let val = val >> shift;
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, format!("\"{:#x}\"", val));
+        // U520 uses `u8` as the unit
+        let val = nfuint::U520::from(&val) << shift;
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, format!("\"{:#x}\"", val));
+        assert_eq!(serde_json::from_str::<nfuint::U520>(&json).unwrap(), val);
+    }
+
     #[test]
     fn deserialize(ref json in "\"0x([[:xdigit:]&&[^0]][[:xdigit:]]{0,63}|0)\"") {
         let result = serde_json::from_str::<nfuint::U256>(&json);
@@ -70,3 +83,142 @@ check_de_error!(
     "\"0x[[:xdigit:]&&[^0]][[:xdigit:]]{64,}\"",
     "invalid length"
 );
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Reprs {
+    #[serde(with = "nfuint::serde::dec")]
+    dec: nfuint::U256,
+    #[serde(with = "nfuint::serde::hex_padded")]
+    hex_padded: nfuint::U128,
+    #[serde(with = "nfuint::serde::be_bytes")]
+    be_bytes: nfuint::U128,
+    #[serde(with = "nfuint::serde::number")]
+    number: nfuint::U256,
+    #[serde(with = "nfuint::serde::lenient")]
+    lenient: nfuint::U256,
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Lenient(#[serde(with = "nfuint::serde::lenient")] nfuint::U256);
+
+#[test]
+fn serde_helpers_json() {
+    let reprs = Reprs {
+        dec: nfuint::U256::from(1234u16),
+        hex_padded: nfuint::U128::from(0x4d2u16),
+        be_bytes: nfuint::U128::from(0x0102u16),
+        number: nfuint::U256::from(u64::MAX),
+        lenient: nfuint::U256::from(0x4d2u16),
+    };
+    let json = serde_json::to_string(&reprs).unwrap();
+    assert_eq!(
+        json,
+        "{\"dec\":\"1234\",\
+         \"hex_padded\":\"0x000000000000000000000000000004d2\",\
+         \"be_bytes\":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,2],\
+         \"number\":18446744073709551615,\
+         \"lenient\":\"0x4d2\"}"
+    );
+    assert_eq!(serde_json::from_str::<Reprs>(&json).unwrap(), reprs);
+    let reprs = Reprs {
+        number: nfuint::U256::from(u64::MAX) + 1u8,
+        ..reprs
+    };
+    let json = serde_json::to_string(&reprs).unwrap();
+    assert!(json.contains("\"number\":\"18446744073709551616\""));
+    assert_eq!(serde_json::from_str::<Reprs>(&json).unwrap(), reprs);
+}
+
+#[test]
+fn serde_helpers_binary() {
+    let reprs = Reprs {
+        dec: nfuint::U256::max_value(),
+        hex_padded: nfuint::U128::from(0x4d2u16),
+        be_bytes: nfuint::U128::from(0x0102u16),
+        number: nfuint::U256::one(),
+        lenient: nfuint::U256::zero(),
+    };
+    let bytes = bincode::serialize(&reprs).unwrap();
+    // each field is a length-prefixed byte string with the full width
+    assert_eq!(bytes.len(), 8 * 5 + 32 * 3 + 16 * 2);
+    assert_eq!(&bytes[8..40], &[0xffu8; 32][..]);
+    assert_eq!(bincode::deserialize::<Reprs>(&bytes).unwrap(), reprs);
+}
+
+#[test]
+fn serde_helpers_lenient() {
+    let expected = Lenient(nfuint::U256::from(1234u16));
+    for json in &[
+        "1234",
+        "\"1234\"",
+        "\"001234\"",
+        "\"0x4d2\"",
+        "\"0X4D2\"",
+        "\"0x00000000000004d2\"",
+        "[4, 210]",
+    ] {
+        assert_eq!(serde_json::from_str::<Lenient>(json).unwrap(), expected);
+    }
+    assert_eq!(
+        serde_json::from_str::<Lenient>("\"0x0\"").unwrap(),
+        Lenient(nfuint::U256::zero())
+    );
+    for json in &["-1", "\"\"", "\"0x\"", "\"12a\"", "\"0xg\"", "1.5"] {
+        assert!(serde_json::from_str::<Lenient>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn serde_helpers_errors() {
+    #[derive(Debug, serde::Deserialize)]
+    struct HexPadded(#[serde(with = "nfuint::serde::hex_padded")] nfuint::U128);
+    #[derive(Debug, serde::Deserialize)]
+    struct Dec(#[serde(with = "nfuint::serde::dec")] nfuint::U128);
+    #[derive(Debug, serde::Deserialize)]
+    struct BeBytes(#[serde(with = "nfuint::serde::be_bytes")] nfuint::U128);
+
+    assert!(serde_json::from_str::<HexPadded>("\"0x4d2\"")
+        .map(|x| x.0)
+        .is_err());
+    assert!(
+        serde_json::from_str::<HexPadded>("\"000000000000000000000000000004d2\"")
+            .map(|x| x.0)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<Dec>("1234").map(|x| x.0).is_err());
+    assert!(serde_json::from_str::<Dec>("\"0x4d2\"")
+        .map(|x| x.0)
+        .is_err());
+    assert!(
+        serde_json::from_str::<Dec>("\"340282366920938463463374607431768211456\"")
+            .map(|x| x.0)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<BeBytes>("[1, 2]")
+        .map(|x| x.0)
+        .is_err());
+    assert!(
+        serde_json::from_str::<BeBytes>(&format!("{:?}", vec![0u8; 17]))
+            .map(|x| x.0)
+            .is_err()
+    );
+}
+
+proptest! {
+    #[test]
+    fn serde_helpers_roundtrip(ref le in any::<props::U256LeBytes>(), shift in 0u32..256) {
+        let val: nfuint::U256 = le.into();
+        let val = val >> shift;
+        let reprs = Reprs {
+            dec: val.clone(),
+            hex_padded: nfuint::U128::from(val.low_u128()),
+            be_bytes: nfuint::U128::from(val.low_u128()),
+            number: val.clone(),
+            lenient: val,
+        };
+        let json = serde_json::to_string(&reprs).unwrap();
+        assert_eq!(serde_json::from_str::<Reprs>(&json).unwrap(), reprs);
+        let bytes = bincode::serialize(&reprs).unwrap();
+        assert_eq!(bincode::deserialize::<Reprs>(&bytes).unwrap(), reprs);
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::op_ref)]
+
+use nfuint::{U256, U4096};
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn to_float(val in any::<u128>(), shift in 0u32..128) {
+        let x = U256::from(val);
+        assert_eq!(x.to_f64(), val as f64);
+        assert_eq!(x.to_f32(), val as f32);
+        let y = &x << shift;
+        let expected = val as f64 * 2f64.powi(shift as i32);
+        assert_eq!(y.to_f64(), expected);
+    }
+
+    #[test]
+    fn from_float(val in 0u64..(1 << 53), shift in 0u32..(256 - 53)) {
+        let x = U256::from(val) << shift;
+        let y = U256::try_from_f64(x.to_f64()).unwrap();
+        assert_eq!(x, y);
+        let frac = val as f64 + 0.5;
+        assert_eq!(U256::from_f64(frac), U256::from(frac as u64));
+    }
+
+    #[test]
+    fn ratio(lhs in 0u64..(1 << 53), rhs in 1u64..(1 << 53), shift in 0u32..(256 - 53)) {
+        let expected = lhs as f64 / rhs as f64;
+        let x = U256::from(lhs) << shift;
+        let y = U256::from(rhs) << shift;
+        assert_eq!(x.ratio_to_f64(&y), expected);
+        assert_eq!(U256::from(lhs).ratio_to_f64(&y), expected / 2f64.powi(shift as i32));
+    }
+}
+
+#[test]
+fn to_float_rounding() {
+    let base = U256::one() << 200;
+    let half_ulp = U256::one() << 147;
+    let x: U256 = &base + &half_ulp;
+    assert_eq!(x.to_f64(), 2f64.powi(200));
+    let x: U256 = &x + &U256::one();
+    assert_eq!(x.to_f64(), 2f64.powi(200) + 2f64.powi(148));
+    let x: U256 = &base + &half_ulp + &half_ulp + &half_ulp;
+    assert_eq!(x.to_f64(), 2f64.powi(200) + 2f64.powi(149));
+    assert_eq!(U256::max_value().to_f64(), 2f64.powi(256));
+    assert_eq!(U256::max_value().to_f32(), f32::INFINITY);
+    assert_eq!(U4096::max_value().to_f64(), f64::INFINITY);
+    let x: U4096 = (U4096::one() << 1024) - (U4096::one() << 970);
+    assert_eq!(x.to_f64(), f64::INFINITY);
+    let x: U4096 = x - U4096::one();
+    assert_eq!(x.to_f64(), f64::MAX);
+}
+
+#[test]
+fn ratio_tiny() {
+    let one = U4096::one();
+    let max = U4096::max_value();
+    assert_eq!(one.ratio_to_f64(&(&one << 1000)), 2f64.powi(-1000));
+    assert_eq!(one.ratio_to_f64(&max), 0.0);
+    assert_eq!(max.ratio_to_f64(&max), 1.0);
+    assert_eq!(U256::from(2u8).ratio_to_f64(&U256::from(3u8)), 2.0 / 3.0);
+}
+
+#[test]
+fn from_float_errors() {
+    assert!(U256::try_from_f64(f64::NAN).is_err());
+    assert!(U256::try_from_f64(-1.0).is_err());
+    assert!(U256::try_from_f64(f64::INFINITY).is_err());
+    assert!(U256::try_from_f64(2f64.powi(256)).is_err());
+    assert_eq!(
+        U256::try_from_f64(2f64.powi(255)).unwrap(),
+        U256::one() << 255
+    );
+    assert_eq!(U256::try_from_f64(0.75).unwrap(), U256::zero());
+    assert_eq!(U256::try_from_f64(-0.0).unwrap(), U256::zero());
+}
+
+#[test]
+#[should_panic]
+fn from_float_nan() {
+    let _ = U256::from_f64(f64::NAN);
+}
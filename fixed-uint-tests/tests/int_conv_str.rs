@@ -34,3 +34,185 @@ conv_from_str!(from_oct_str, "{:o}");
 conv_from_str!(from_hex_str, "{:x}", from_lowerhex_str);
 conv_from_str!(from_hex_str, "{:X}", from_upperhex_str);
 conv_from_str!(from_dec_str, "{}");
+
+macro_rules! conv_from_str_prefixed {
+    ($name:ident, $fmt_str:expr) => {
+        proptest! {
+            #[test]
+            fn $name(ref le in any::<props::U256LeBytes>(), sep in 1usize..8) {
+                let origin: nfuint::U256 = le.into();
+                let origin_str = format!($fmt_str, origin);
+                let result: nfuint::U256 = origin_str.parse().unwrap();
+                assert_eq!(origin, result);
+                let separated = origin_str
+                    .chars()
+                    .enumerate()
+                    .fold(String::new(), |mut s, (i, c)| {
+                        if i % sep == 0 {
+                            s.push('_');
+                        }
+                        s.push(c);
+                        s
+                    });
+                let result: nfuint::U256 = separated.parse().unwrap();
+                assert_eq!(origin, result);
+            }
+        }
+    };
+}
+
+conv_from_str_prefixed!(from_str_bin, "{:#b}");
+conv_from_str_prefixed!(from_str_oct, "0o{:o}");
+conv_from_str_prefixed!(from_str_hex, "{:#x}");
+conv_from_str_prefixed!(from_str_dec, "{}");
+
+proptest! {
+    #[test]
+    fn from_str_radix(ref le in any::<props::U256LeBytes>(), radix in 2u32..=36) {
+        let origin: nfuint::U256 = le.into();
+        let big: num_bigint::BigUint = le.into();
+        let result = nfuint::U256::from_str_radix(&big.to_str_radix(radix), radix).unwrap();
+        assert_eq!(origin, result);
+        let upper = big.to_str_radix(radix).to_uppercase();
+        let result = nfuint::U256::from_str_radix(&upper, radix).unwrap();
+        assert_eq!(origin, result);
+    }
+}
+
+#[test]
+fn from_str_errors() {
+    use std::str::FromStr;
+    assert!(nfuint::U256::from_str("").is_err());
+    assert!(nfuint::U256::from_str("_").is_err());
+    assert!(nfuint::U256::from_str("0x").is_err());
+    assert!(nfuint::U256::from_str("0xg").is_err());
+    assert!(nfuint::U256::from_str("0b2").is_err());
+    assert!(nfuint::U256::from_str("-1").is_err());
+    assert!(nfuint::U256::from_str("0\u{e9}").is_err());
+    assert!(nfuint::U256::from_str("1\u{e9}1").is_err());
+    assert_eq!(nfuint::U256::from_str("0").unwrap(), nfuint::U256::zero());
+    assert_eq!(nfuint::U256::from_str("1_000").unwrap(), 1000u32);
+    let max = format!("{:#b}", nfuint::U256::max_value());
+    assert!(nfuint::U256::from_str(&max).is_ok());
+    assert!(nfuint::U256::from_str(&format!("{}1", max)).is_err());
+    assert!(nfuint::U256::from_str_radix("z", 35).is_err());
+    assert!(nfuint::U256::from_str_radix("00", 7).is_err());
+}
+
+#[test]
+#[should_panic]
+fn from_str_radix_invalid_radix() {
+    let _ = nfuint::U256::from_str_radix("1", 37);
+}
+
+proptest! {
+    #[test]
+    fn to_str_radix(ref le in any::<props::U256LeBytes>(), radix in 2u32..=36) {
+        let origin: nfuint::U256 = le.into();
+        let big: num_bigint::BigUint = le.into();
+        assert_eq!(origin.to_str_radix(radix), big.to_str_radix(radix));
+    }
+
+    #[test]
+    fn to_digits(ref le in any::<props::U256LeBytes>(), radix in 2u32..=256, shift in 0u32..256) {
+        let origin: nfuint::U256 = le.into();
+        let origin = origin >> shift;
+        let big: num_bigint::BigUint = (&props::U256LeBytes::from(&origin)).into();
+        let expected = big.to_radix_le(radix);
+        let digits_le = origin.to_digits_le(radix).collect::<Vec<_>>();
+        assert_eq!(digits_le, expected);
+        let digits_be = origin.to_digits_be(radix).collect::<Vec<_>>();
+        assert_eq!(digits_be, big.to_radix_be(radix));
+        assert_eq!(origin.to_digits_be(radix).len(), expected.len());
+    }
+}
+
+#[test]
+fn to_digits_zero() {
+    let zero = nfuint::U256::zero();
+    assert_eq!(zero.to_digits_le(62).collect::<Vec<_>>(), vec![0]);
+    assert_eq!(zero.to_str_radix(36), "0");
+    let x = nfuint::U128::from(62u16 * 62 + 1);
+    assert_eq!(x.to_digits_be(62).collect::<Vec<_>>(), vec![1, 0, 1]);
+}
+
+proptest! {
+    #[test]
+    fn to_str_radix_small_unit(ref le in any::<props::U256LeBytes>(), radix in 2u32..=36) {
+        // U520 uses `u8` as the unit
+        let origin: nfuint::U520 = nfuint::U520::from(&nfuint::U256::from(le)) << 200;
+        let hex = format!("{:x}", origin);
+        let big = num_bigint::BigUint::parse_bytes(hex.as_bytes(), 16).unwrap();
+        assert_eq!(origin.to_str_radix(radix), big.to_str_radix(radix));
+        assert_eq!(origin.to_digits_le(256).collect::<Vec<_>>(), big.to_radix_le(256));
+    }
+}
+
+proptest! {
+    #[test]
+    fn write_dec_to(ref le in any::<props::U256LeBytes>(), shift in 0u32..256) {
+        let origin: nfuint::U256 = le.into();
+        let origin = origin >> shift;
+        let mut buf = [0u8; 80];
+        assert_eq!(origin.write_dec_to(&mut buf[..]), origin.to_string());
+    }
+
+    #[test]
+    fn from_dec_str_small_unit(ref le in any::<props::U256LeBytes>(), shift in 0u32..=264) {
+        // U520 uses `u8` as the unit
+        let origin: nfuint::U520 = nfuint::U520::from(&nfuint::U256::from(le)) << shift;
+        let result = nfuint::U520::from_dec_str(&origin.to_string()).unwrap();
+        assert_eq!(origin, result);
+    }
+}
+
+#[test]
+fn from_dec_str_chunks() {
+    let max = nfuint::U256::max_value();
+    let max_str = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    assert_eq!(nfuint::U256::from_dec_str(max_str).unwrap(), max);
+    let over = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+    assert!(nfuint::U256::from_dec_str(over).is_err());
+    let x = nfuint::U256::from_dec_str("10000000000000000000").unwrap();
+    assert_eq!(x, nfuint::U256::from(10_000_000_000_000_000_000u128));
+    let x = nfuint::U256::from_dec_str("18446744073709551616").unwrap();
+    assert_eq!(x, nfuint::U256::from(1u128 << 64));
+    assert!(nfuint::U256::from_dec_str("1844674407370955161a").is_err());
+}
+
+#[test]
+fn dec_str_shift_126() {
+    let x = nfuint::U256::from_hex_str(
+        "7d314a1a2429fd59ac07be3363171d03b51052e9259a0d648bac0bbf5f7b3b7b",
+    )
+    .unwrap();
+    let origin = &x >> 126u32;
+    let mut buf = [0u8; 80];
+    assert_eq!(origin.write_dec_to(&mut buf[..]), origin.to_string());
+    let origin: nfuint::U520 = nfuint::U520::from(&x) << 126u32;
+    let result = nfuint::U520::from_dec_str(&origin.to_string()).unwrap();
+    assert_eq!(origin, result);
+}
+
+#[test]
+fn write_dec_to_exact() {
+    let mut buf = [b'x'; 4];
+    assert_eq!(nfuint::U256::from(1234u16).write_dec_to(&mut buf), "1234");
+    assert_eq!(nfuint::U256::zero().write_dec_to(&mut buf), "0");
+    assert_eq!(&buf, b"0234");
+}
+
+#[test]
+#[should_panic]
+fn write_dec_to_short_buffer() {
+    let mut buf = [0u8; 3];
+    let _ = nfuint::U256::from(1234u16).write_dec_to(&mut buf);
+}
+
+#[test]
+fn from_dec_str_max() {
+    let max = nfuint::U160::max_value();
+    assert_eq!(nfuint::U160::from_dec_str(&max.to_string()).unwrap(), max);
+    let max = nfuint::U520::max_value();
+    assert_eq!(nfuint::U520::from_dec_str(&max.to_string()).unwrap(), max);
+}
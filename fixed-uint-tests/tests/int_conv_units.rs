@@ -0,0 +1,123 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::{FormatUnitsOptions, U256};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+#[test]
+fn format_units() {
+    let x = U256::from(1_234_567_890u64);
+    assert_eq!(x.format_units(0), "1234567890");
+    assert_eq!(x.format_units(4), "123456.7890");
+    assert_eq!(x.format_units(10), "0.1234567890");
+    assert_eq!(x.format_units(12), "0.001234567890");
+    assert_eq!(U256::zero().format_units(3), "0.000");
+    assert_eq!(U256::zero().format_units(0), "0");
+}
+
+#[test]
+fn format_units_with() {
+    let x = U256::from(1_234_567_890_000u64);
+    let opts = FormatUnitsOptions {
+        trim_zeros: true,
+        ..Default::default()
+    };
+    assert_eq!(x.format_units_with(6, &opts), "1234567.89");
+    assert_eq!(x.format_units_with(4, &opts), "123456789");
+    let opts = FormatUnitsOptions {
+        thousands_separator: Some(','),
+        ..Default::default()
+    };
+    assert_eq!(x.format_units_with(6, &opts), "1,234,567.890000");
+    assert_eq!(x.format_units_with(7, &opts), "123,456.7890000");
+    assert_eq!(x.format_units_with(10, &opts), "123.4567890000");
+    let opts = FormatUnitsOptions {
+        max_fraction_digits: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(x.format_units_with(6, &opts), "1234567.89");
+    assert_eq!(x.format_units_with(9, &opts), "1234.56");
+    let opts = FormatUnitsOptions {
+        trim_zeros: true,
+        thousands_separator: Some('_'),
+        max_fraction_digits: Some(3),
+    };
+    assert_eq!(x.format_units_with(12, &opts), "1.234");
+    assert_eq!(x.format_units_with(16, &opts), "0");
+    assert_eq!(
+        U256::from(1_000_000u32).format_units_with(0, &opts),
+        "1_000_000"
+    );
+}
+
+#[test]
+fn parse_units() {
+    let x = U256::from(1_500_000_000_000_000_000u64);
+    assert_eq!(U256::parse_units("1.5", 18).unwrap(), x);
+    assert_eq!(U256::parse_units("1.500", 18).unwrap(), x);
+    assert_eq!(U256::parse_units("01.5", 18).unwrap(), x);
+    assert_eq!(U256::parse_units(".5", 1).unwrap(), U256::from(5u8));
+    assert_eq!(U256::parse_units("5.", 1).unwrap(), U256::from(50u8));
+    assert_eq!(U256::parse_units("0.05", 2).unwrap(), U256::from(5u8));
+    assert_eq!(U256::parse_units("0.000", 0).unwrap(), U256::zero());
+    assert_eq!(U256::parse_units("7", 0).unwrap(), U256::from(7u8));
+    assert!(U256::parse_units("0.05", 1).is_err());
+    assert!(U256::parse_units("", 1).is_err());
+    assert!(U256::parse_units(".", 1).is_err());
+    assert!(U256::parse_units("1.2.3", 4).is_err());
+    assert!(U256::parse_units("1a", 4).is_err());
+    assert!(U256::parse_units("1", 78).is_err());
+    assert_eq!(U256::parse_units("0", 78).unwrap(), U256::zero());
+    let max = U256::max_value();
+    assert_eq!(U256::parse_units(&max.format_units(30), 30).unwrap(), max);
+    let over = format!("{}0", max.format_units(30));
+    assert!(U256::parse_units(&over, 31).is_err());
+}
+
+proptest! {
+    #[test]
+    fn units_roundtrip(ref le in any::<props::U256LeBytes>(), decimals in 0u32..=80) {
+        let origin: U256 = le.into();
+        let result = U256::parse_units(&origin.format_units(decimals), decimals).unwrap();
+        assert_eq!(origin, result);
+        let opts = FormatUnitsOptions { trim_zeros: true, ..Default::default() };
+        let result = U256::parse_units(&origin.format_units_with(decimals, &opts), decimals).unwrap();
+        assert_eq!(origin, result);
+    }
+}
+
+#[test]
+fn from_sci_str() {
+    let x = U256::from(1_500_000_000_000_000_000u64);
+    assert_eq!(U256::from_sci_str("1.5e18").unwrap(), x);
+    assert_eq!(U256::from_sci_str("1.5E+18").unwrap(), x);
+    assert_eq!(U256::from_sci_str("15e17").unwrap(), x);
+    assert!(U256::from_sci_str("150e-2").is_err());
+    assert_eq!(U256::from_sci_str("1500e-2").unwrap(), U256::from(15u8));
+    assert_eq!(U256::from_sci_str("42").unwrap(), U256::from(42u8));
+    assert_eq!(U256::from_sci_str("0e-100").unwrap(), U256::zero());
+    assert!(U256::from_sci_str("1.5e0").is_err());
+    assert!(U256::from_sci_str("15e-1").is_err());
+    assert!(U256::from_sci_str("1e-100").is_err());
+    assert!(U256::from_sci_str("1e78").is_err());
+    assert!(U256::from_sci_str("1e").is_err());
+    assert!(U256::from_sci_str("1e1x").is_err());
+    assert!(U256::from_sci_str("1e99999999999").is_err());
+}
+
+proptest! {
+    #[test]
+    fn sci_roundtrip(ref le in any::<props::U256LeBytes>()) {
+        let origin: U256 = le.into();
+        let result = U256::from_sci_str(&format!("{:e}", origin)).unwrap();
+        assert_eq!(origin, result);
+        let result = U256::from_sci_str(&format!("{:E}", origin)).unwrap();
+        assert_eq!(origin, result);
+    }
+}
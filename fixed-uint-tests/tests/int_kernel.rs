@@ -6,8 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use nfuint::{prelude::UintConvert, U128, U256};
+use nfuint::{prelude::UintConvert, NarrowingError, U128, U256, U512};
 use nfuint_tests::tools;
+use std::convert::TryFrom;
 
 const U128_ZERO: U128 = U128::zero();
 const U128_ONE: U128 = U128::one();
@@ -53,3 +54,23 @@ fn convert() {
     assert!(!t);
     assert_eq!(x, n_u256_lo);
 }
+
+#[test]
+fn convert_std() {
+    let r = tools::gen_nonzero::<u64>();
+    let n_u128 = U128::max_value() - U128::from(r);
+    let n_u256 = U256::from(&n_u128);
+    assert_eq!(U512::from(n_u256.clone()), U512::from(&n_u128));
+    assert_eq!(U128::try_from(&n_u256).unwrap(), n_u128);
+
+    let n_u256 = U256::max_value() - U256::from(r);
+    assert!(U128::try_from(&n_u256).is_err());
+    assert!(matches!(
+        U256::try_from(U512::max_value()),
+        Err(NarrowingError::Overflow(511))
+    ));
+    let x: U128 = n_u256.truncate_into();
+    assert_eq!(x, U128::max_value() - U128::from(r));
+    let y: U512 = n_u256.truncate_into();
+    assert_eq!(U256::try_from(y).unwrap(), n_u256);
+}
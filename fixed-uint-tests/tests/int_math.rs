@@ -6,11 +6,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use nfuint::U256;
+use nfuint::{U1024, U2048, U256, U4096};
 use nfuint_tests::props;
 use num_bigint::BigUint;
 use num_integer::Integer;
-use proptest::{prelude::any_with, proptest};
+use proptest::{
+    prelude::{any, any_with},
+    proptest,
+};
 
 proptest! {
     #[test]
@@ -23,3 +26,60 @@ proptest! {
         println!("x = {:x}", x);
     }
 }
+
+proptest! {
+    #[test]
+    fn ilog10(ref le in any::<props::U256LeBytes>()) {
+        let x: U256 = le.into();
+        let y: BigUint = le.into();
+        if y.bits() == 0 {
+            assert_eq!(x.checked_ilog10(), None);
+        } else {
+            assert_eq!(x.ilog10() as usize, y.to_str_radix(10).len() - 1);
+        }
+    }
+}
+
+#[test]
+fn ilog10_boundary() {
+    let ten = U256::from(10u8);
+    for exp in 1..78 {
+        let power = ten.pow(exp);
+        assert_eq!(power.ilog10(), exp);
+        assert_eq!((&power - 1u8).ilog10(), exp - 1);
+    }
+    assert_eq!(U256::one().ilog10(), 0);
+    assert_eq!(U256::max_value().ilog10(), 77);
+    assert_eq!(U256::zero().checked_ilog10(), None);
+}
+
+macro_rules! check_ilog10_bit_lengths {
+    ($name:ident, $uint:ident, $bits:expr) => {
+        #[test]
+        fn $name() {
+            for bits in 1..=$bits {
+                let max = $uint::max_value() >> ($bits - bits);
+                let min = $uint::one() << (bits - 1);
+                for x in &[max, min] {
+                    let y = BigUint::parse_bytes(format!("{:x}", x).as_bytes(), 16).unwrap();
+                    assert_eq!(x.ilog10() as usize, y.to_str_radix(10).len() - 1);
+                }
+            }
+        }
+    };
+}
+
+check_ilog10_bit_lengths!(ilog10_bit_lengths_u1024, U1024, 1024u32);
+check_ilog10_bit_lengths!(ilog10_bit_lengths_u2048, U2048, 2048u32);
+check_ilog10_bit_lengths!(ilog10_bit_lengths_u4096, U4096, 4096u32);
+
+#[test]
+fn ilog10_short_estimate() {
+    // the estimate from the bit length is less than the result at these bit lengths
+    let x = U1024::max_value() >> (1024u32 - 681);
+    assert_eq!(x.ilog10(), 205);
+    let x = U2048::max_value() >> (2048u32 - 1651);
+    assert_eq!(x.ilog10(), 497);
+    let x = U4096::max_value() >> (4096u32 - 1166);
+    assert_eq!(x.ilog10(), 351);
+}
@@ -39,3 +39,40 @@ proptest! {
         assert_eq!(result_nfuint, (false, true, true, true, false));
     }
 }
+
+proptest! {
+    #[test]
+    fn primitive(ref le in any::<props::U256LeBytes>(), val in any::<u64>(), shift in 0u32..=256) {
+        let lhs: nfuint::U256 = le.into();
+        let lhs = if shift == 256 { nfuint::U256::zero() } else { lhs >> shift };
+        let result_nfuint = (lhs > val, lhs >= val, lhs == val, lhs <= val, lhs < val);
+        let rhs = nfuint::U256::from(val);
+        let expected = (lhs > rhs, lhs >= rhs, lhs == rhs, lhs <= rhs, lhs < rhs);
+        assert_eq!(expected, result_nfuint);
+        let result_reverse = (val < lhs, val <= lhs, val == lhs, val >= lhs, val > lhs);
+        assert_eq!(expected, result_reverse);
+        assert_eq!(lhs == u128::from(val), lhs == val);
+        let rhs_u8 = nfuint::U256::from(val as u8);
+        assert_eq!(lhs < (val as u8), lhs < rhs_u8);
+    }
+
+    #[test]
+    fn cross_size(ref pair in any_with::<props::U256Pair>(props::U256PairParameters::Random), shift in 0u32..256) {
+        let (lhs, rhs): (nfuint::U256, nfuint::U256) = pair.into();
+        let rhs = rhs >> shift;
+        let expected = (lhs > rhs, lhs >= rhs, lhs == rhs, lhs <= rhs, lhs < rhs);
+        let rhs_u512 = nfuint::U512::from(&rhs);
+        let rhs_u520 = nfuint::U520::from(&rhs);
+        let result_u512 = (lhs > rhs_u512, lhs >= rhs_u512, lhs == rhs_u512, lhs <= rhs_u512, lhs < rhs_u512);
+        let result_u520 = (lhs > rhs_u520, lhs >= rhs_u520, lhs == rhs_u520, lhs <= rhs_u520, lhs < rhs_u520);
+        assert_eq!(expected, result_u512);
+        assert_eq!(expected, result_u520);
+        if let Ok(rhs_u128) = std::convert::TryFrom::try_from(&rhs) {
+            let rhs_u128: nfuint::U128 = rhs_u128;
+            let result = (lhs > rhs_u128, lhs >= rhs_u128, lhs == rhs_u128, lhs <= rhs_u128, lhs < rhs_u128);
+            assert_eq!(expected, result);
+            let result = (rhs_u128 < lhs, rhs_u128 <= lhs, rhs_u128 == lhs, rhs_u128 >= lhs, rhs_u128 > lhs);
+            assert_eq!(expected, result);
+        }
+    }
+}
@@ -11,6 +11,7 @@ extern crate proptest;
 
 use nfuint_tests::props;
 use proptest::prelude::any;
+use std::convert::TryFrom;
 
 macro_rules! std_convert_from {
     ($name:ident, $from_type:ty) => {
@@ -39,7 +40,7 @@ proptest! {
         } else {
             nfuint::U256::zero()
         };
-        assert_eq!(expected, val.into());
+        assert_eq!(expected, nfuint::U256::from(val));
     }
 }
 
@@ -64,3 +65,68 @@ proptest! {
         assert_eq!(expected, result);
     }
 }
+
+macro_rules! std_convert_try_from_into {
+    ($name:ident, $prim_type:ty) => {
+        proptest! {
+            #[test]
+            fn $name(val in any::<$prim_type>()) {
+                let result = nfuint::U256::try_from(val);
+                if val < 0 {
+                    assert!(result.is_err());
+                } else {
+                    let uint = result.unwrap();
+                    assert_eq!(<$prim_type>::try_from(&uint).unwrap(), val);
+                    let overflow = uint + (nfuint::U256::one() << (<$prim_type>::MAX.count_ones()));
+                    assert!(<$prim_type>::try_from(overflow).is_err());
+                }
+            }
+        }
+    };
+    ($name:ident, $prim_type:ty, unsigned) => {
+        proptest! {
+            #[test]
+            fn $name(val in any::<$prim_type>()) {
+                let uint = nfuint::U256::from(val);
+                assert_eq!(<$prim_type>::try_from(&uint).unwrap(), val);
+                let overflow = uint + (nfuint::U256::one() << (<$prim_type>::MAX.count_ones()));
+                assert!(<$prim_type>::try_from(overflow).is_err());
+            }
+        }
+    };
+}
+
+std_convert_try_from_into!(try_into_u8, u8, unsigned);
+std_convert_try_from_into!(try_into_u16, u16, unsigned);
+std_convert_try_from_into!(try_into_u32, u32, unsigned);
+std_convert_try_from_into!(try_into_u64, u64, unsigned);
+std_convert_try_from_into!(try_into_u128, u128, unsigned);
+std_convert_try_from_into!(try_from_into_i8, i8);
+std_convert_try_from_into!(try_from_into_i16, i16);
+std_convert_try_from_into!(try_from_into_i32, i32);
+std_convert_try_from_into!(try_from_into_i64, i64);
+std_convert_try_from_into!(try_from_into_i128, i128);
+std_convert_try_from_into!(try_from_into_isize, isize);
+
+proptest! {
+    #[test]
+    fn low_and_as_prim(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&le.inner[..16]);
+        let low = u128::from_le_bytes(bytes);
+        assert_eq!(val.low_u128(), low);
+        assert_eq!(val.low_u64(), low as u64);
+        let small = nfuint::U256::from(low as u64);
+        assert_eq!(small.as_u64(), low as u64);
+        assert_eq!(small.as_usize(), low as usize);
+        assert_eq!(usize::try_from(&small).unwrap(), low as usize);
+    }
+}
+
+#[test]
+#[should_panic]
+fn as_u64_overflow() {
+    let val = nfuint::U256::from(u64::MAX) + nfuint::U256::one();
+    let _ = val.as_u64();
+}
@@ -14,7 +14,7 @@ macro_rules! check_default {
         $( check_default!($uint); )+
     }};
     ($uint:ident) => {
-        assert_eq!(nfuint::$uint::default(), 0u8.into());
+        assert_eq!(nfuint::$uint::default(), nfuint::$uint::from(0u8));
     };
 }
 
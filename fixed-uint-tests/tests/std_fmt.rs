@@ -44,31 +44,47 @@ std_fmt!(display_random, "{}");
 
 #[test]
 fn debug() {
+    check_fmt!("{:?}", nfuint::U128::from(0u128), "0 (0x0)");
+    check_fmt!("{:?}", nfuint::U256::from(1u128), "1 (0x1)");
     check_fmt!(
         "{:?}",
-        nfuint::U128::from(0u128),
+        nfuint::U256::from(0x0001_0000_0000_0000_0001u128),
+        "18446744073709551617 (0x10000000000000001)"
+    );
+    check_fmt!("{:?}", Some(nfuint::U256::from(255u8)), "Some(255 (0xff))");
+}
+
+#[test]
+fn debug_limbs() {
+    check_fmt!(
+        "{:?}",
+        nfuint::U128::from(0u128).debug_limbs(),
         "U128 ( [ 0x0000000000000000, 0x0000000000000000 ] )"
     );
     check_fmt!(
         "{:?}",
-        nfuint::U256::from(0u128),
+        nfuint::U256::from(0u128).debug_limbs(),
         "U256 ( [ 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000 ] )"
     );
     check_fmt!(
         "{:?}",
-        nfuint::U256::from(1u128),
+        nfuint::U256::from(1u128).debug_limbs(),
         "U256 ( [ 0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000 ] )"
     );
     check_fmt!(
         "{:?}",
-        nfuint::U256::from(0x0001_0000_0000_0000_0000u128),
+        nfuint::U256::from(0x0001_0000_0000_0000_0000u128).debug_limbs(),
         "U256 ( [ 0x0000000000000000, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000 ] )"
     );
     check_fmt!(
         "{:?}",
-        nfuint::U256::from(0x0001_0000_0000_0000_0001u128),
+        nfuint::U256::from(0x0001_0000_0000_0000_0001u128).debug_limbs(),
         "U256 ( [ 0x0000000000000001, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000 ] )"
     );
+    let val = nfuint::U128::from(0x0002_0000_0000_0000_0001u128);
+    let expected = "U128 ( [\n    0x0000000000000001,\n    0x0000000000000002,\n]\n";
+    check_fmt!("{:#?}", val, expected);
+    check_fmt!("{:#?}", val.debug_limbs(), expected);
 }
 
 #[test]
@@ -172,3 +188,91 @@ fn alternate() {
     check_fmt!("{:#x}", val, "0xfedcba");
     check_fmt!("{:#X}", val, "0xFEDCBA");
 }
+
+macro_rules! std_fmt_padding {
+    ($name:ident, [$($fmt_str:expr),+]) => {
+        proptest! {
+            #[test]
+            fn $name(val in any::<u128>(), shift in 0u32..128) {
+                let val = val >> shift;
+                let nfval = nfuint::U256::from(val);
+                $(
+                    assert_eq!(format!($fmt_str, val), format!($fmt_str, nfval));
+                )+
+            }
+        }
+    };
+}
+
+std_fmt_padding!(
+    padding_display,
+    ["{:40}", "{:<40}", "{:^40}", "{:>40}", "{:*^40}", "{:040}", "{:+}", "{:+045}", "{:2}"]
+);
+std_fmt_padding!(
+    padding_binary,
+    [
+        "{:140b}",
+        "{:<140b}",
+        "{:-^140b}",
+        "{:#0140b}",
+        "{:0140b}",
+        "{:+#b}"
+    ]
+);
+std_fmt_padding!(
+    padding_octal,
+    ["{:50o}", "{:<50o}", "{:_^50o}", "{:#050o}", "{:050o}", "{:+#o}"]
+);
+std_fmt_padding!(
+    padding_hex,
+    ["{:40x}", "{:<40X}", "{:.^40x}", "{:#066x}", "{:040X}", "{:+#x}", "{:#3X}"]
+);
+std_fmt_padding!(
+    padding_exp,
+    [
+        "{:e}",
+        "{:E}",
+        "{:.0e}",
+        "{:.3e}",
+        "{:.10E}",
+        "{:.45e}",
+        "{:50e}",
+        "{:<30.2e}",
+        "{:~^30.4E}",
+        "{:+030.1e}",
+        "{:+e}"
+    ]
+);
+
+#[test]
+fn exp() {
+    let val = nfuint::U256::max_value();
+    check_fmt!(
+        "{:e}",
+        val,
+        "1.15792089237316195423570985008687907853269984665640564039457584007913129639935e77"
+    );
+    check_fmt!("{:.3e}", val, "1.158e77");
+    check_fmt!("{:.0E}", val, "1E77");
+    check_fmt!(
+        "{:.80e}",
+        val,
+        "1.15792089237316195423570985008687907853269984665640564039457584007913129639935000e77"
+    );
+    let val = nfuint::U256::from(9_995u32) << 200;
+    check_fmt!("{:.2e}", nfuint::U256::from(9_995u32), "1.00e4");
+    check_fmt!("{:.2e}", nfuint::U256::from(9_985u32), "9.98e3");
+    check_fmt!("{:.2e}", nfuint::U256::from(9_975u32), "9.98e3");
+    check_fmt!(
+        "{:e}",
+        val,
+        "1.606134575236860780404191111294992021220941892285901438883725312e64"
+    );
+    check_fmt!("{:.1e}", val, "1.6e64");
+    check_fmt!("{:e}", nfuint::U256::zero(), "0e0");
+    check_fmt!("{:.2e}", nfuint::U256::zero(), "0.00e0");
+    // 206 digits, the bit length underestimates the exponent
+    let val = nfuint::U1024::max_value() >> (1024u32 - 681);
+    check_fmt!("{:.3e}", val, "1.003e205");
+    check_fmt!("{:.0E}", val, "1E205");
+}
@@ -54,6 +54,25 @@ macro_rules! std_ops_unary {
     };
 }
 
+macro_rules! std_ops_assign {
+    ($opr:tt, $opr_assign:tt, $name:ident, $param:ident) => {
+        proptest! {
+            #[test]
+            fn $name(ref pair in any_with::<props::U256Pair>(props::U256PairParameters::$param)) {
+                let (lhs, rhs): (nfuint::U256, nfuint::U256) = pair.into();
+                let expected = &lhs $opr &rhs;
+                assert_eq!(lhs $opr &rhs, expected);
+                let mut x = lhs;
+                x $opr_assign rhs;
+                assert_eq!(x, expected);
+                let mut x = lhs;
+                x $opr_assign &rhs;
+                assert_eq!(x, expected);
+            }
+        }
+    };
+}
+
 std_ops_binary!(+, add, CanAdd);
 std_ops_binary!(-, sub, CanSub);
 std_ops_binary!(*, mul, CanMul);
@@ -64,3 +83,25 @@ std_ops_binary!(|, bitor, Random);
 std_ops_binary!(^, bitxor, Random);
 
 std_ops_unary!(!, not);
+
+std_ops_assign!(+, +=, add_assign, CanAdd);
+std_ops_assign!(-, -=, sub_assign, CanSub);
+std_ops_assign!(*, *=, mul_assign, CanMul);
+std_ops_assign!(/, /=, div_assign, CanDiv);
+std_ops_assign!(%, %=, rem_assign, CanRem);
+std_ops_assign!(&, &=, bitand_assign, Random);
+std_ops_assign!(|, |=, bitor_assign, Random);
+std_ops_assign!(^, ^=, bitxor_assign, Random);
+
+#[test]
+fn assign_overflow_keeps_lhs() {
+    let max = nfuint::U256::max_value();
+    let mut x = max;
+    let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| x += 2u8));
+    assert!(result.is_err());
+    assert_eq!(x, max);
+    let mut x = nfuint::U256::one();
+    let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| x -= &max));
+    assert!(result.is_err());
+    assert_eq!(x, nfuint::U256::one());
+}
@@ -14,28 +14,42 @@
 //!
 //! [numext-fixed-uint]: https://docs.rs/numext-fixed-uint
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 extern crate constructor;
 
+#[cfg(feature = "std")]
 use thiserror::Error;
 
 constructor::construct_fixed_uints!(
     U128 {
         size = 128,
+        copy = true,
     },
     U160 {
         size = 160,
+        copy = true,
     },
     U224 {
         size = 224,
+        copy = true,
     },
     U256 {
         size = 256,
+        copy = true,
     },
     U384 {
         size = 384,
+        copy = true,
     },
     U512 {
         size = 512,
+        copy = true,
     },
     U520 {
         size = 520,
@@ -39,12 +39,30 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # `no_std`
+//!
+//! This crate is `no_std` when the default feature `std` is disabled.
+//!
+//! - The feature `alloc` enables the APIs which return allocated values, such as `to_str_radix`.
+//! - The feature `std` enables `alloc`, and implements `std::error::Error` for the errors.
+//! - The features `support_rand`, `support_heapsize`, `support_serde`, `support_arbitrary`,
+//!   `support_proptest` and `support_quickcheck` require `std`.
+
+#![no_std]
 
 extern crate nfuint_core;
 extern crate nfuint_hack;
 
 pub use nfuint_core::prelude;
-pub use nfuint_core::{FixedUintError, FromSliceError, FromStrError, IntoSliceError};
+#[cfg(feature = "support_serde")]
+pub use nfuint_core::serde_helpers as serde;
+#[cfg(feature = "support_proptest")]
+pub use nfuint_core::PairParameters;
+pub use nfuint_core::{
+    FixedUintError, FormatUnitsOptions, FromFloatError, FromPrimitiveError, FromSliceError,
+    FromStrError, IntoPrimitiveError, IntoSliceError, NarrowingError,
+};
 
 macro_rules! reexport {
     ([$(($name:ident, $macro_name:ident),)+]) => {
@@ -0,0 +1,47 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use numext_fuzz::{dispatch_uint, BigUintConv, Hash, Uint};
+
+macro_rules! check_uint_serde {
+    ($name:ident, $val:ident) => {{
+        let json = serde_json::to_string(&$val).unwrap();
+        assert_eq!(
+            json,
+            format!("\"0x{}\"", $val.to_biguint().to_str_radix(16))
+        );
+        let val: nfuint::$name = serde_json::from_str(&json).unwrap();
+        assert_eq!(val, $val);
+    }};
+}
+
+macro_rules! check_hash_serde {
+    ($input:ident, [$($name:ident),+]) => {
+        match $input {
+            $(
+                Hash::$name(hash) => {
+                    let json = serde_json::to_string(&hash).unwrap();
+                    let val: nfhash::$name = serde_json::from_str(&json).unwrap();
+                    assert_eq!(val, hash);
+                }
+            )+
+        }
+    };
+}
+
+fuzz_target!(|input: (Uint, Hash)| {
+    let (uint, hash) = input;
+    dispatch_uint!(Uint, uint, check_uint_serde);
+    check_hash_serde!(
+        hash,
+        [H128, H160, H224, H256, H384, H512, H520, H1024, H2048, H4096]
+    );
+});
@@ -0,0 +1,46 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use numext_fuzz::{dispatch_uint, modulus, BigUintConv, UintPair};
+
+macro_rules! check_arith {
+    ($name:ident, $lhs:ident, $rhs:ident) => {{
+        type Uint = nfuint::$name;
+        let m = modulus(<Uint as BigUintConv>::BITS);
+        let a = $lhs.to_biguint();
+        let b = $rhs.to_biguint();
+        let check = |(ret, of): (Uint, bool), expected: BigUint, overflow: bool| {
+            assert_eq!(ret, Uint::from_biguint(&expected));
+            assert_eq!(of, overflow);
+        };
+        check($lhs.overflowing_add(&$rhs), &a + &b, &a + &b >= m);
+        check($lhs.overflowing_sub(&$rhs), &a + &m - &b, a < b);
+        check($lhs.overflowing_mul(&$rhs), &a * &b, &a * &b >= m);
+        assert_eq!($lhs.checked_add(&$rhs).is_some(), &a + &b < m);
+        assert_eq!($lhs.checked_sub(&$rhs).is_some(), a >= b);
+        assert_eq!($lhs.checked_mul(&$rhs).is_some(), &a * &b < m);
+        if b.is_zero() {
+            assert!($lhs.checked_div(&$rhs).is_none());
+            assert!($lhs.checked_rem(&$rhs).is_none());
+        } else {
+            check($lhs.overflowing_div(&$rhs), &a / &b, false);
+            check($lhs.overflowing_rem(&$rhs), &a % &b, false);
+        }
+        assert_eq!(&$lhs & &$rhs, Uint::from_biguint(&(&a & &b)));
+        assert_eq!(&$lhs | &$rhs, Uint::from_biguint(&(&a | &b)));
+        assert_eq!(&$lhs ^ &$rhs, Uint::from_biguint(&(&a ^ &b)));
+        assert_eq!($lhs.cmp(&$rhs), a.cmp(&b));
+    }};
+}
+
+fuzz_target!(|input: UintPair| dispatch_uint!(UintPair, input, check_arith));
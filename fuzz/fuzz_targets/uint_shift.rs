@@ -0,0 +1,35 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use numext_fuzz::{dispatch_uint, modulus, BigUintConv, UintWithNumber};
+
+macro_rules! check_shift {
+    ($name:ident, $val:ident, $num:ident) => {{
+        type Uint = nfuint::$name;
+        let bits = <Uint as BigUintConv>::BITS;
+        let a = $val.to_biguint();
+        let shift = $num as usize;
+        let (shl, shl_of) = $val.overflowing_shl($num as u128);
+        let (shr, shr_of) = $val.overflowing_shr($num as u128);
+        assert_eq!(shl_of, shift >= bits);
+        assert_eq!(shr_of, shift >= bits);
+        assert_eq!(shl, Uint::from_biguint(&(&a << (shift % bits))));
+        assert_eq!(shr, Uint::from_biguint(&(&a >> (shift % bits))));
+        assert_eq!(&$val << $num, Uint::from_biguint(&(&a << shift)));
+        assert_eq!(&$val >> $num, Uint::from_biguint(&(&a >> shift)));
+        let pow = $val.overflowing_pow(u32::from($num % 64));
+        let expected = num_traits::pow(a, usize::from($num % 64));
+        assert_eq!(pow.0, Uint::from_biguint(&expected));
+        assert_eq!(pow.1, expected >= modulus(bits));
+    }};
+}
+
+fuzz_target!(|input: UintWithNumber| dispatch_uint!(UintWithNumber, input, check_shift));
@@ -0,0 +1,127 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use numext_fuzz::{dispatch_uint, modulus, BigUintConv, UintWithNumber};
+
+// The value of a decimal string which is scaled by `10^exp`, if it's an integer.
+fn scaled_decimal(input: &str, exp: i64) -> Option<BigUint> {
+    let (int_part, frac_part) = match input.find('.') {
+        Some(idx) => (&input[..idx], &input[(idx + 1)..]),
+        None => (input, ""),
+    };
+    let digits = format!("{}{}", int_part, frac_part);
+    let value = if digits.bytes().all(|b| b == b'0') {
+        return Some(BigUint::zero());
+    } else {
+        BigUint::parse_bytes(digits.as_bytes(), 10)?
+    };
+    let exp = exp - frac_part.len() as i64;
+    let ten = BigUint::from(10u8);
+    if exp >= 0 {
+        Some(value * num_traits::pow(ten, exp as usize))
+    } else {
+        let power = num_traits::pow(ten, -exp as usize);
+        if (&value % &power).is_zero() {
+            Some(value / power)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! check_str {
+    ($name:ident, $val:ident, $num:ident) => {{
+        type Uint = nfuint::$name;
+        let a = $val.to_biguint();
+        let dec = a.to_str_radix(10);
+        let hex = a.to_str_radix(16);
+        assert_eq!($val.to_string(), dec);
+        assert_eq!(format!("{:x}", &$val), hex);
+        assert_eq!(Uint::from_dec_str(&dec).unwrap(), $val);
+        assert_eq!(Uint::from_hex_str(&hex).unwrap(), $val);
+        let radix = 2 + u32::from($num % 35);
+        let digits = a.to_str_radix(radix);
+        assert_eq!($val.to_str_radix(radix), digits);
+        assert_eq!(Uint::from_str_radix(&digits, radix).unwrap(), $val);
+        assert_eq!(dec.parse::<Uint>().unwrap(), $val);
+        assert_eq!(format!("{:#b}", &$val).parse::<Uint>().unwrap(), $val);
+        assert_eq!(format!("{:#o}", &$val).parse::<Uint>().unwrap(), $val);
+        assert_eq!(format!("{:#x}", &$val).parse::<Uint>().unwrap(), $val);
+        assert_eq!(Uint::from_sci_str(&format!("{:e}", &$val)).unwrap(), $val);
+        assert_eq!(Uint::from_sci_str(&format!("{:E}", &$val)).unwrap(), $val);
+        let decimals = u32::from($num % 100);
+        let units = $val.format_units(decimals);
+        assert_eq!(Uint::parse_units(&units, decimals).unwrap(), $val);
+    }};
+}
+
+macro_rules! check_parse {
+    ($name:ident, $input:ident, $num:ident) => {{
+        type Uint = nfuint::$name;
+        let in_range = |val: BigUint| {
+            Some(val)
+                .filter(|val| val < &modulus(<Uint as BigUintConv>::BITS))
+                .map(|val| Uint::from_biguint(&val))
+        };
+        let radix = 2 + u32::from($num % 35);
+        let result = Uint::from_str_radix($input, radix).ok();
+        // `num-bigint` also accepts a plus sign, underscores and leading zeros.
+        if !$input.is_empty()
+            && $input.chars().all(|chr| chr.is_digit(radix))
+            && ($input.len() == 1 || !$input.starts_with('0'))
+        {
+            let expected = BigUint::parse_bytes($input.as_bytes(), radix);
+            assert_eq!(result, expected.and_then(in_range));
+            if radix == 10 {
+                assert_eq!(Uint::from_dec_str($input).ok(), result);
+            }
+        } else if let Some(val) = result {
+            let expected = BigUint::parse_bytes($input.as_bytes(), radix);
+            assert_eq!(expected.and_then(in_range), Some(val));
+        }
+        if let Ok(val) = $input.parse::<Uint>() {
+            let digits = $input.replace('_', "");
+            let (digits, radix) = match digits.get(..2) {
+                Some("0b") => (&digits[2..], 2),
+                Some("0o") => (&digits[2..], 8),
+                Some("0x") => (&digits[2..], 16),
+                _ => (&digits[..], 10),
+            };
+            let expected = BigUint::parse_bytes(digits.as_bytes(), radix);
+            assert_eq!(expected.and_then(in_range), Some(val));
+        }
+        let decimals = u32::from($num % 100);
+        if let Ok(val) = Uint::parse_units($input, decimals) {
+            let expected = scaled_decimal($input, i64::from(decimals));
+            assert_eq!(expected.and_then(in_range), Some(val));
+        }
+        if let Ok(val) = Uint::from_sci_str($input) {
+            let expected = match $input.find(|chr| chr == 'e' || chr == 'E') {
+                Some(idx) => {
+                    let exp = $input[(idx + 1)..].parse().unwrap();
+                    scaled_decimal(&$input[..idx], exp)
+                }
+                None => scaled_decimal($input, 0),
+            };
+            assert_eq!(expected.and_then(in_range), Some(val));
+        }
+    }};
+}
+
+fuzz_target!(|input: (UintWithNumber, u16, &str)| {
+    let (val, num, s) = input;
+    dispatch_uint!(UintWithNumber, val, check_str);
+    check_parse!(U128, s, num);
+    check_parse!(U256, s, num);
+    check_parse!(U520, s, num);
+});
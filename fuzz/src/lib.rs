@@ -0,0 +1,167 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Common inputs and helpers for the fuzz targets.
+
+// The inputs are short-lived, boxing the large variants is not worth it.
+#![allow(clippy::large_enum_variant)]
+
+use arbitrary::Arbitrary;
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// Convert between fixed uints and `num_bigint::BigUint`.
+pub trait BigUintConv: Sized {
+    const BITS: usize;
+    fn to_biguint(&self) -> BigUint;
+    /// Convert from a big uint, modulo `2^BITS`.
+    fn from_biguint(value: &BigUint) -> Self;
+}
+
+/// Return `2^bits`.
+pub fn modulus(bits: usize) -> BigUint {
+    BigUint::one() << bits
+}
+
+macro_rules! impl_biguint_conv {
+    ($(($name:ident, $bits:expr),)+) => {
+        $(
+            impl BigUintConv for nfuint::$name {
+                const BITS: usize = $bits;
+                fn to_biguint(&self) -> BigUint {
+                    BigUint::from_bytes_le(&self.to_le_bytes()[..])
+                }
+                fn from_biguint(value: &BigUint) -> Self {
+                    let mut bytes = (value % modulus($bits)).to_bytes_le();
+                    bytes.resize($bits / 8, 0);
+                    Self::from_little_endian(&bytes[..]).unwrap()
+                }
+            }
+        )+
+    };
+}
+
+impl_biguint_conv!(
+    (U128, 128),
+    (U160, 160),
+    (U224, 224),
+    (U256, 256),
+    (U384, 384),
+    (U512, 512),
+    (U520, 520),
+    (U1024, 1024),
+    (U2048, 2048),
+    (U4096, 4096),
+);
+
+/// A pair of fixed uints in the same size.
+#[derive(Debug, Arbitrary)]
+pub enum UintPair {
+    U128(nfuint::U128, nfuint::U128),
+    U160(nfuint::U160, nfuint::U160),
+    U224(nfuint::U224, nfuint::U224),
+    U256(nfuint::U256, nfuint::U256),
+    U384(nfuint::U384, nfuint::U384),
+    U512(nfuint::U512, nfuint::U512),
+    U520(nfuint::U520, nfuint::U520),
+    U1024(nfuint::U1024, nfuint::U1024),
+    U2048(nfuint::U2048, nfuint::U2048),
+    U4096(nfuint::U4096, nfuint::U4096),
+}
+
+/// A fixed uint with a number.
+#[derive(Debug, Arbitrary)]
+pub enum UintWithNumber {
+    U128(nfuint::U128, u16),
+    U160(nfuint::U160, u16),
+    U224(nfuint::U224, u16),
+    U256(nfuint::U256, u16),
+    U384(nfuint::U384, u16),
+    U512(nfuint::U512, u16),
+    U520(nfuint::U520, u16),
+    U1024(nfuint::U1024, u16),
+    U2048(nfuint::U2048, u16),
+    U4096(nfuint::U4096, u16),
+}
+
+/// A fixed uint.
+#[derive(Debug, Arbitrary)]
+pub enum Uint {
+    U128(nfuint::U128),
+    U160(nfuint::U160),
+    U224(nfuint::U224),
+    U256(nfuint::U256),
+    U384(nfuint::U384),
+    U512(nfuint::U512),
+    U520(nfuint::U520),
+    U1024(nfuint::U1024),
+    U2048(nfuint::U2048),
+    U4096(nfuint::U4096),
+}
+
+/// Apply a macro to the fixed uints in an input, with the name of their type.
+#[macro_export]
+macro_rules! dispatch_uint {
+    (UintPair, $input:expr, $check:ident) => {
+        match $input {
+            $crate::UintPair::U128(lhs, rhs) => $check!(U128, lhs, rhs),
+            $crate::UintPair::U160(lhs, rhs) => $check!(U160, lhs, rhs),
+            $crate::UintPair::U224(lhs, rhs) => $check!(U224, lhs, rhs),
+            $crate::UintPair::U256(lhs, rhs) => $check!(U256, lhs, rhs),
+            $crate::UintPair::U384(lhs, rhs) => $check!(U384, lhs, rhs),
+            $crate::UintPair::U512(lhs, rhs) => $check!(U512, lhs, rhs),
+            $crate::UintPair::U520(lhs, rhs) => $check!(U520, lhs, rhs),
+            $crate::UintPair::U1024(lhs, rhs) => $check!(U1024, lhs, rhs),
+            $crate::UintPair::U2048(lhs, rhs) => $check!(U2048, lhs, rhs),
+            $crate::UintPair::U4096(lhs, rhs) => $check!(U4096, lhs, rhs),
+        }
+    };
+    (UintWithNumber, $input:expr, $check:ident) => {
+        match $input {
+            $crate::UintWithNumber::U128(val, num) => $check!(U128, val, num),
+            $crate::UintWithNumber::U160(val, num) => $check!(U160, val, num),
+            $crate::UintWithNumber::U224(val, num) => $check!(U224, val, num),
+            $crate::UintWithNumber::U256(val, num) => $check!(U256, val, num),
+            $crate::UintWithNumber::U384(val, num) => $check!(U384, val, num),
+            $crate::UintWithNumber::U512(val, num) => $check!(U512, val, num),
+            $crate::UintWithNumber::U520(val, num) => $check!(U520, val, num),
+            $crate::UintWithNumber::U1024(val, num) => $check!(U1024, val, num),
+            $crate::UintWithNumber::U2048(val, num) => $check!(U2048, val, num),
+            $crate::UintWithNumber::U4096(val, num) => $check!(U4096, val, num),
+        }
+    };
+    (Uint, $input:expr, $check:ident) => {
+        match $input {
+            $crate::Uint::U128(val) => $check!(U128, val),
+            $crate::Uint::U160(val) => $check!(U160, val),
+            $crate::Uint::U224(val) => $check!(U224, val),
+            $crate::Uint::U256(val) => $check!(U256, val),
+            $crate::Uint::U384(val) => $check!(U384, val),
+            $crate::Uint::U512(val) => $check!(U512, val),
+            $crate::Uint::U520(val) => $check!(U520, val),
+            $crate::Uint::U1024(val) => $check!(U1024, val),
+            $crate::Uint::U2048(val) => $check!(U2048, val),
+            $crate::Uint::U4096(val) => $check!(U4096, val),
+        }
+    };
+}
+
+/// A fixed hash.
+#[derive(Debug, Arbitrary)]
+pub enum Hash {
+    H128(nfhash::H128),
+    H160(nfhash::H160),
+    H224(nfhash::H224),
+    H256(nfhash::H256),
+    H384(nfhash::H384),
+    H512(nfhash::H512),
+    H520(nfhash::H520),
+    H1024(nfhash::H1024),
+    H2048(nfhash::H2048),
+    H4096(nfhash::H4096),
+}